@@ -0,0 +1,51 @@
+//! Generates the example fixture tests.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+
+/// Lists every example fixture under `examples/dayNN/` that has a `.expected` sidecar, as an
+/// `example_tests!` invocation for `src/fixtures.rs` to include.
+fn main() {
+    let examples = Path::new(env!("CARGO_MANIFEST_DIR")).join("../examples");
+    println!("cargo:rerun-if-changed={}", examples.display());
+
+    let mut fixtures = Vec::new();
+    for day_dir in read_sorted(&examples) {
+        let Some(day) = day_dir
+            .file_name()
+            .and_then(|name| name.to_str()?.strip_prefix("day")?.parse::<u32>().ok())
+        else {
+            continue;
+        };
+        for path in read_sorted(&day_dir) {
+            let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) else {
+                continue;
+            };
+            if path.extension().is_some_and(|ext| ext == "txt")
+                && path.with_extension("expected").is_file()
+            {
+                let ident: String = name
+                    .chars()
+                    .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+                    .collect();
+                fixtures.push(format!("    day{day:02}_{ident}: {day}, \"{name}\";\n"));
+            }
+        }
+    }
+
+    let generated = format!("example_tests! {{\n{}}}\n", fixtures.concat());
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("example_tests.rs");
+    fs::write(out, generated).unwrap();
+}
+
+fn read_sorted(dir: &Path) -> Vec<std::path::PathBuf> {
+    let mut paths: Vec<_> = match fs::read_dir(dir) {
+        Ok(entries) => entries
+            .filter_map(|entry| Some(entry.ok()?.path()))
+            .collect(),
+        Err(_) => Vec::new(),
+    };
+    paths.sort();
+    paths
+}
@@ -0,0 +1,128 @@
+use aoc2025::error::AocError;
+use aoc2025::helpers;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::ErrorKind;
+
+pub const ANSWERS_FILE: &str = "aoc2025/answers.txt";
+
+#[derive(Debug, PartialEq)]
+pub enum Check<'a> {
+    Matches,
+    Differs(&'a str),
+    Unknown,
+}
+
+/// Accepted answers, stored one `day part answer` entry per line.
+#[derive(Debug, Default, PartialEq)]
+pub struct AnswerStore {
+    answers: BTreeMap<(u32, u32), String>,
+}
+
+impl AnswerStore {
+    pub fn load(path: &str) -> Result<AnswerStore, AocError> {
+        match fs::read_to_string(path) {
+            Ok(contents) => AnswerStore::parse(&contents),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(AnswerStore::default()),
+            Err(e) => Err(AocError::io(path, e)),
+        }
+    }
+
+    pub fn parse(contents: &str) -> Result<AnswerStore, AocError> {
+        let mut answers = BTreeMap::new();
+        for (line, text) in contents.lines().enumerate() {
+            let text = text.trim_end_matches('\r');
+            if text.is_empty() || text.starts_with('#') {
+                continue;
+            }
+            let fields: Vec<&str> = text.splitn(3, ' ').collect();
+            let [day, part, answer] = fields[..] else {
+                return Err(AocError::malformed(
+                    line,
+                    0,
+                    format!("expected 'day part answer', found '{text}'"),
+                ));
+            };
+            answers.insert(
+                (
+                    helpers::parse_number(day, line, 0)?,
+                    helpers::parse_number(part, line, day.len() + 1)?,
+                ),
+                answer.to_string(),
+            );
+        }
+        Ok(AnswerStore { answers })
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), AocError> {
+        let mut contents = String::from("# day part answer\n");
+        for ((day, part), answer) in &self.answers {
+            contents.push_str(&format!("{day} {part} {answer}\n"));
+        }
+        fs::write(path, contents).map_err(|e| AocError::io(path, e))
+    }
+
+    pub fn get(&self, day: u32, part: u32) -> Option<&str> {
+        self.answers.get(&(day, part)).map(|answer| answer.as_str())
+    }
+
+    pub fn record(&mut self, day: u32, part: u32, answer: &str) {
+        self.answers.insert((day, part), answer.to_string());
+    }
+
+    pub fn check(&self, day: u32, part: u32, answer: &str) -> Check<'_> {
+        match self.get(day, part) {
+            Some(expected) if expected == answer => Check::Matches,
+            Some(expected) => Check::Differs(expected),
+            None => Check::Unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_skips_comments_and_blank_lines() {
+        let store = AnswerStore::parse("# day part answer\n\n8 2 25272\n4 1 13\n").unwrap();
+        assert_eq!(store.get(8, 2), Some("25272"));
+        assert_eq!(store.get(4, 1), Some("13"));
+        assert_eq!(store.get(4, 2), None);
+    }
+
+    #[test]
+    fn parse_reports_bad_lines() {
+        assert_eq!(
+            AnswerStore::parse("8 2 25272\n8 two 40\n")
+                .unwrap_err()
+                .to_string(),
+            "line 2, col 3: expected a number, found 'two'"
+        );
+        assert_eq!(
+            AnswerStore::parse("8 2\n").unwrap_err().to_string(),
+            "line 1, col 1: expected 'day part answer', found '8 2'"
+        );
+    }
+
+    #[test]
+    fn check_against_recorded_answers() {
+        let mut store = AnswerStore::default();
+        store.record(8, 2, "25272");
+        assert_eq!(store.check(8, 2, "25272"), Check::Matches);
+        assert_eq!(store.check(8, 2, "40"), Check::Differs("25272"));
+        assert_eq!(store.check(8, 1, "40"), Check::Unknown);
+    }
+
+    #[test]
+    fn saved_store_parses_back() {
+        let mut store = AnswerStore::default();
+        store.record(9, 1, "50");
+        store.record(1, 2, "6");
+        let path = std::env::temp_dir().join(format!("aoc2025-answers-{}.txt", std::process::id()));
+        let path = path.to_str().unwrap();
+        store.save(path).unwrap();
+        assert_eq!(AnswerStore::load(path).unwrap(), store);
+        fs::remove_file(path).unwrap();
+    }
+}
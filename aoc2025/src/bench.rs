@@ -0,0 +1,272 @@
+use crate::cli::BenchArgs;
+use crate::config::Config;
+use crate::inputs::InputDirs;
+use crate::runner;
+use aoc2025::error::AocError;
+use aoc2025::json::Json;
+use aoc2025::registry::Day;
+use aoc2025::solution::Params;
+use std::collections::HashMap;
+use std::fs;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub std_dev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let middle = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[middle - 1] + sorted[middle]) / 2
+        } else {
+            sorted[middle]
+        };
+        let nanos: Vec<f64> = sorted.iter().map(|d| d.as_nanos() as f64).collect();
+        let mean = nanos.iter().sum::<f64>() / nanos.len() as f64;
+        let variance = if nanos.len() > 1 {
+            nanos.iter().map(|n| (n - mean).powi(2)).sum::<f64>() / (nanos.len() - 1) as f64
+        } else {
+            0.0
+        };
+        Stats {
+            min: sorted[0],
+            median,
+            mean: Duration::from_nanos(mean.round() as u64),
+            std_dev: Duration::from_nanos(variance.sqrt().round() as u64),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct StageStats {
+    pub day: u32,
+    pub stage: String,
+    pub stats: Stats,
+}
+
+type Baseline = HashMap<(u32, String), Stats>;
+
+pub fn bench(args: BenchArgs, config: &Config) -> Result<(), String> {
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    let baseline = match &args.baseline {
+        Some(path) => Some(load_baseline(path).map_err(|e| format!("{path}, {e}"))?),
+        None => None,
+    };
+    let dirs = InputDirs::load(args.input_dir.as_deref(), config);
+    let mut results = Vec::new();
+    for day in runner::selected_days(&args.days)? {
+        let stages = runner::read_input(day, None, &dirs)
+            .and_then(|lines| {
+                let params = config.params(day.number, &Params::new());
+                bench_day(day, &lines, &params, &parts, args.warmup, args.iterations)
+            })
+            .map_err(|e| format!("day {}, {e}", day.number))?;
+        results.extend(stages);
+    }
+    print_results(&results, baseline.as_ref(), args.iterations);
+    if let Some(path) = &args.save_baseline {
+        fs::write(path, baseline_json(&results)).map_err(|e| AocError::io(path, e).to_string())?;
+        println!("saved baseline to {path}");
+    }
+    Ok(())
+}
+
+fn bench_day(
+    day: &Day,
+    lines: &[String],
+    params: &Params,
+    parts: &[u32],
+    warmup: u32,
+    iterations: u32,
+) -> Result<Vec<StageStats>, AocError> {
+    for _ in 0..warmup {
+        (day.run)(lines.to_vec(), params, parts)?;
+    }
+    let mut parse_samples = Vec::new();
+    let mut part_samples: Vec<Vec<Duration>> = vec![Vec::new(); parts.len()];
+    for _ in 0..iterations {
+        let run = (day.run)(lines.to_vec(), params, parts)?;
+        parse_samples.push(run.parse_time);
+        for (samples, part) in part_samples.iter_mut().zip(run.parts) {
+            if part
+                .answer
+                .map_err(|e| AocError::Invalid(format!("part {}, {e}", part.part)))?
+                .is_some()
+            {
+                samples.push(part.elapsed);
+            }
+        }
+    }
+    let mut stages = vec![StageStats {
+        day: day.number,
+        stage: "parse".to_string(),
+        stats: Stats::from_samples(&parse_samples),
+    }];
+    for (part, samples) in parts.iter().zip(&part_samples) {
+        if !samples.is_empty() {
+            stages.push(StageStats {
+                day: day.number,
+                stage: format!("part{part}"),
+                stats: Stats::from_samples(samples),
+            });
+        }
+    }
+    Ok(stages)
+}
+
+fn print_results(results: &[StageStats], baseline: Option<&Baseline>, iterations: u32) {
+    println!("{iterations} iterations per day");
+    println!(
+        "{:>3}  {:>5}  {:>12}  {:>12}  {:>12}  {:>12}  {:>9}",
+        "day", "stage", "min", "median", "mean", "std dev", "vs base"
+    );
+    for result in results {
+        let change = baseline
+            .and_then(|baseline| baseline.get(&(result.day, result.stage.clone())))
+            .map_or("-".to_string(), |base| {
+                change_from(base.median, result.stats.median)
+            });
+        println!(
+            "{:>3}  {:>5}  {:>12}  {:>12}  {:>12}  {:>12}  {:>9}",
+            result.day,
+            result.stage,
+            format!("{:.2?}", result.stats.min),
+            format!("{:.2?}", result.stats.median),
+            format!("{:.2?}", result.stats.mean),
+            format!("{:.2?}", result.stats.std_dev),
+            change
+        );
+    }
+}
+
+/// How much `current` differs from `base` as a percentage, or `-` when a base of zero gives
+/// nothing to compare against.
+fn change_from(base: Duration, current: Duration) -> String {
+    if base.is_zero() {
+        return "-".to_string();
+    }
+    let base = base.as_nanos() as f64;
+    let current = current.as_nanos() as f64;
+    format!("{:+.1}%", (current - base) / base * 100.0)
+}
+
+fn nanos(duration: Duration) -> Json {
+    Json::Number(duration.as_nanos() as f64)
+}
+
+fn baseline_json(results: &[StageStats]) -> String {
+    let rows: Vec<String> = results
+        .iter()
+        .map(|result| {
+            Json::Object(vec![
+                ("day".to_string(), Json::Number(result.day as f64)),
+                ("stage".to_string(), Json::String(result.stage.clone())),
+                ("min_ns".to_string(), nanos(result.stats.min)),
+                ("median_ns".to_string(), nanos(result.stats.median)),
+                ("mean_ns".to_string(), nanos(result.stats.mean)),
+                ("std_dev_ns".to_string(), nanos(result.stats.std_dev)),
+            ])
+            .to_string()
+        })
+        .collect();
+    format!("[\n{}\n]\n", rows.join(",\n"))
+}
+
+fn load_baseline(path: &str) -> Result<Baseline, AocError> {
+    let contents = fs::read_to_string(path).map_err(|e| AocError::io(path, e))?;
+    parse_baseline(&contents)
+}
+
+fn parse_baseline(contents: &str) -> Result<Baseline, AocError> {
+    let json = Json::parse(contents)?;
+    let rows = json.as_array().ok_or(AocError::Invalid(
+        "expected an array of stage results".to_string(),
+    ))?;
+    let mut baseline = HashMap::new();
+    for (index, row) in rows.iter().enumerate() {
+        let number = |key: &str| {
+            row.get(key)
+                .and_then(Json::as_f64)
+                .ok_or(AocError::Invalid(format!(
+                    "entry {} is missing '{key}'",
+                    index + 1
+                )))
+        };
+        let duration = |key: &str| number(key).map(|n| Duration::from_nanos(n as u64));
+        let stage = row
+            .get("stage")
+            .and_then(Json::as_str)
+            .ok_or(AocError::Invalid(format!(
+                "entry {} is missing 'stage'",
+                index + 1
+            )))?;
+        let stats = Stats {
+            min: duration("min_ns")?,
+            median: duration("median_ns")?,
+            mean: duration("mean_ns")?,
+            std_dev: duration("std_dev_ns")?,
+        };
+        baseline.insert((number("day")? as u32, stage.to_string()), stats);
+    }
+    Ok(baseline)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|v| Duration::from_millis(*v)).collect()
+    }
+
+    #[test]
+    fn stats_from_odd_and_even_samples() {
+        let stats = Stats::from_samples(&millis(&[5, 1, 3]));
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.mean, Duration::from_millis(3));
+        assert_eq!(stats.std_dev, Duration::from_millis(2));
+
+        let stats = Stats::from_samples(&millis(&[4, 1, 3, 2]));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+    }
+
+    #[test]
+    fn single_sample_has_no_deviation() {
+        let stats = Stats::from_samples(&millis(&[7]));
+        assert_eq!(stats.std_dev, Duration::ZERO);
+        assert_eq!(stats.median, Duration::from_millis(7));
+    }
+
+    #[test]
+    fn change_against_the_base_median() {
+        let [fast, slow] = [Duration::from_millis(40), Duration::from_millis(50)];
+        assert_eq!(change_from(fast, slow), "+25.0%");
+        assert_eq!(change_from(slow, fast), "-20.0%");
+        assert_eq!(change_from(Duration::ZERO, slow), "-");
+    }
+
+    #[test]
+    fn baseline_round_trips() {
+        let results = vec![StageStats {
+            day: 8,
+            stage: "part2".to_string(),
+            stats: Stats::from_samples(&millis(&[40, 42, 47])),
+        }];
+        let baseline = parse_baseline(&baseline_json(&results)).unwrap();
+        assert_eq!(
+            baseline.get(&(8, "part2".to_string())),
+            Some(&results[0].stats)
+        );
+    }
+}
@@ -0,0 +1,442 @@
+use aoc2025::solution::{Params, TraceFormat};
+
+pub const USAGE: &str = "usage:
+  aoc2025 run --day <n> [--part <1|2>] [--input <path>|-] [--param <name>=<value>]...
+              [--input-dir <path>] [--save]
+  aoc2025 run --all [--part <1|2>] [--input-dir <path>] [--save]
+  aoc2025 verify [--input-dir <path>]
+  aoc2025 trace --day <n> [--format <text|json>] [--input <path>|-]
+                [--param <name>=<value>]... [--input-dir <path>]
+  aoc2025 new --day <n>
+  aoc2025 bench (--day <n>|--all) [--part <1|2>] [--warmup <n>] [--iterations <n>]
+                [--baseline <path>] [--save-baseline <path>] [--input-dir <path>]
+
+Inputs are looked up as dayNN.txt in --input-dir, then $AOC_INPUT_DIR, then input_dir
+in aoc.toml, then inputs/2025.";
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run(RunArgs),
+    Verify(VerifyArgs),
+    Bench(BenchArgs),
+    Trace(TraceArgs),
+    New(NewArgs),
+}
+
+#[derive(Debug, PartialEq)]
+pub enum DaySelection {
+    One(u32),
+    All,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum InputSource {
+    File(String),
+    Stdin,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct RunArgs {
+    pub days: DaySelection,
+    pub part: Option<u32>,
+    pub input: Option<InputSource>,
+    pub params: Params,
+    pub input_dir: Option<String>,
+    pub save: bool,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct VerifyArgs {
+    pub input_dir: Option<String>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct TraceArgs {
+    pub day: u32,
+    pub format: TraceFormat,
+    pub input: Option<InputSource>,
+    pub params: Params,
+    pub input_dir: Option<String>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct NewArgs {
+    pub day: u32,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct BenchArgs {
+    pub days: DaySelection,
+    pub part: Option<u32>,
+    pub warmup: u32,
+    pub iterations: u32,
+    pub baseline: Option<String>,
+    pub save_baseline: Option<String>,
+    pub input_dir: Option<String>,
+}
+
+pub fn parse_args(args: Vec<String>) -> Result<Command, String> {
+    let mut iter = args.into_iter();
+    match iter.next().as_deref() {
+        Some("run") => parse_run_args(iter).map(Command::Run),
+        Some("verify") => parse_verify_args(iter).map(Command::Verify),
+        Some("bench") => parse_bench_args(iter).map(Command::Bench),
+        Some("trace") => parse_trace_args(iter).map(Command::Trace),
+        Some("new") => parse_new_args(iter).map(Command::New),
+        Some(other) => Err(format!("unknown command '{other}'")),
+        None => Err("no command given".to_string()),
+    }
+}
+
+fn parse_run_args(mut iter: impl Iterator<Item = String>) -> Result<RunArgs, String> {
+    let mut day = None;
+    let mut all = false;
+    let mut part = None;
+    let mut input = None;
+    let mut params = Params::new();
+    let mut input_dir = None;
+    let mut save = false;
+    while let Some(flag) = iter.next() {
+        match flag.as_str() {
+            "--day" => day = Some(parse_number(&flag, iter.next())?),
+            "--part" => part = Some(parse_part(iter.next())?),
+            "--input" => input = Some(parse_input(iter.next())?),
+            "--param" => parse_param(iter.next(), &mut params)?,
+            "--input-dir" => input_dir = Some(parse_path(&flag, iter.next())?),
+            "--all" => all = true,
+            "--save" => save = true,
+            other => return Err(format!("unknown argument '{other}'")),
+        }
+    }
+    let days = day_selection(day, all)?;
+    if days == DaySelection::All && input.is_some() {
+        return Err("--input cannot be used with --all".to_string());
+    }
+    if days == DaySelection::All && !params.is_empty() {
+        return Err("--param cannot be used with --all".to_string());
+    }
+    if save && (input.is_some() || !params.is_empty()) {
+        return Err("--save only records answers for the default input and parameters".to_string());
+    }
+    Ok(RunArgs {
+        days,
+        part,
+        input,
+        params,
+        input_dir,
+        save,
+    })
+}
+
+fn parse_bench_args(mut iter: impl Iterator<Item = String>) -> Result<BenchArgs, String> {
+    let mut day = None;
+    let mut all = false;
+    let mut part = None;
+    let mut warmup = 3;
+    let mut iterations = 20;
+    let mut baseline = None;
+    let mut save_baseline = None;
+    let mut input_dir = None;
+    while let Some(flag) = iter.next() {
+        match flag.as_str() {
+            "--day" => day = Some(parse_number(&flag, iter.next())?),
+            "--all" => all = true,
+            "--part" => part = Some(parse_part(iter.next())?),
+            "--warmup" => warmup = parse_number(&flag, iter.next())?,
+            "--iterations" => iterations = parse_number(&flag, iter.next())?,
+            "--baseline" => baseline = Some(parse_path(&flag, iter.next())?),
+            "--save-baseline" => save_baseline = Some(parse_path(&flag, iter.next())?),
+            "--input-dir" => input_dir = Some(parse_path(&flag, iter.next())?),
+            other => return Err(format!("unknown argument '{other}'")),
+        }
+    }
+    if iterations == 0 {
+        return Err("--iterations must be at least 1".to_string());
+    }
+    Ok(BenchArgs {
+        days: day_selection(day, all)?,
+        part,
+        warmup,
+        iterations,
+        baseline,
+        save_baseline,
+        input_dir,
+    })
+}
+
+fn parse_trace_args(mut iter: impl Iterator<Item = String>) -> Result<TraceArgs, String> {
+    let mut day = None;
+    let mut format = TraceFormat::default();
+    let mut input = None;
+    let mut params = Params::new();
+    let mut input_dir = None;
+    while let Some(flag) = iter.next() {
+        match flag.as_str() {
+            "--day" => day = Some(parse_number(&flag, iter.next())?),
+            "--format" => format = parse_format(iter.next())?,
+            "--input" => input = Some(parse_input(iter.next())?),
+            "--param" => parse_param(iter.next(), &mut params)?,
+            "--input-dir" => input_dir = Some(parse_path(&flag, iter.next())?),
+            other => return Err(format!("unknown argument '{other}'")),
+        }
+    }
+    Ok(TraceArgs {
+        day: day.ok_or("--day is required")?,
+        format,
+        input,
+        params,
+        input_dir,
+    })
+}
+
+fn parse_new_args(mut iter: impl Iterator<Item = String>) -> Result<NewArgs, String> {
+    let mut day = None;
+    while let Some(flag) = iter.next() {
+        match flag.as_str() {
+            "--day" => day = Some(parse_number(&flag, iter.next())?),
+            other => return Err(format!("unknown argument '{other}'")),
+        }
+    }
+    match day {
+        Some(day @ 1..=25) => Ok(NewArgs { day }),
+        Some(day) => Err(format!("--day must be between 1 and 25, got {day}")),
+        None => Err("--day is required".to_string()),
+    }
+}
+
+fn parse_verify_args(mut iter: impl Iterator<Item = String>) -> Result<VerifyArgs, String> {
+    let mut input_dir = None;
+    while let Some(flag) = iter.next() {
+        match flag.as_str() {
+            "--input-dir" => input_dir = Some(parse_path(&flag, iter.next())?),
+            other => return Err(format!("unknown argument '{other}'")),
+        }
+    }
+    Ok(VerifyArgs { input_dir })
+}
+
+fn day_selection(day: Option<u32>, all: bool) -> Result<DaySelection, String> {
+    match (day, all) {
+        (Some(day), false) => Ok(DaySelection::One(day)),
+        (None, true) => Ok(DaySelection::All),
+        (Some(_), true) => Err("--day and --all cannot be used together".to_string()),
+        (None, false) => Err("either --day or --all is required".to_string()),
+    }
+}
+
+fn parse_path(flag: &str, value: Option<String>) -> Result<String, String> {
+    value.ok_or(format!("{flag} needs a path"))
+}
+
+fn parse_number(flag: &str, value: Option<String>) -> Result<u32, String> {
+    let value = value.ok_or(format!("{flag} needs a value"))?;
+    value
+        .parse()
+        .map_err(|_| format!("{flag} expects a number, got '{value}'"))
+}
+
+fn parse_part(value: Option<String>) -> Result<u32, String> {
+    match parse_number("--part", value)? {
+        part @ (1 | 2) => Ok(part),
+        part => Err(format!("--part must be 1 or 2, got {part}")),
+    }
+}
+
+fn parse_input(value: Option<String>) -> Result<InputSource, String> {
+    match value.as_deref() {
+        Some("-") => Ok(InputSource::Stdin),
+        Some(path) => Ok(InputSource::File(path.to_string())),
+        None => Err("--input needs a path or '-'".to_string()),
+    }
+}
+
+fn parse_format(value: Option<String>) -> Result<TraceFormat, String> {
+    let value = value.ok_or("--format needs a value")?;
+    value.parse().map_err(|e| format!("--format {e}"))
+}
+
+fn parse_param(value: Option<String>, params: &mut Params) -> Result<(), String> {
+    match value.as_deref().and_then(|value| value.split_once('=')) {
+        Some((name, value)) if !name.is_empty() => {
+            params.set(name, value);
+            Ok(())
+        }
+        _ => Err("--param needs a value like name=value".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(|e| e.to_string()).collect()
+    }
+
+    #[test]
+    fn parse_single_day() {
+        assert_eq!(
+            parse_args(args("run --day 8 --part 2 --input day8.txt")),
+            Ok(Command::Run(RunArgs {
+                days: DaySelection::One(8),
+                part: Some(2),
+                input: Some(InputSource::File("day8.txt".to_string())),
+                params: Params::new(),
+                input_dir: None,
+                save: false,
+            }))
+        );
+    }
+
+    #[test]
+    fn parse_stdin_input() {
+        assert_eq!(
+            parse_args(args("run --input - --day 3")),
+            Ok(Command::Run(RunArgs {
+                days: DaySelection::One(3),
+                part: None,
+                input: Some(InputSource::Stdin),
+                params: Params::new(),
+                input_dir: None,
+                save: false,
+            }))
+        );
+    }
+
+    #[test]
+    fn parse_all_days() {
+        assert_eq!(
+            parse_args(args("run --all --save")),
+            Ok(Command::Run(RunArgs {
+                days: DaySelection::All,
+                part: None,
+                input: None,
+                params: Params::new(),
+                input_dir: None,
+                save: true,
+            }))
+        );
+    }
+
+    #[test]
+    fn parse_params() {
+        let mut params = Params::new();
+        params.set("part1_rule", "repeated-exactly-3");
+        params.set("start", "0");
+        assert_eq!(
+            parse_args(args(
+                "run --day 2 --param part1_rule=repeated-exactly-3 --param start=0"
+            )),
+            Ok(Command::Run(RunArgs {
+                days: DaySelection::One(2),
+                part: None,
+                input: None,
+                params,
+                input_dir: None,
+                save: false,
+            }))
+        );
+    }
+
+    #[test]
+    fn parse_verify() {
+        assert_eq!(
+            parse_args(args("verify")),
+            Ok(Command::Verify(VerifyArgs { input_dir: None }))
+        );
+        assert_eq!(
+            parse_args(args("verify --input-dir ../puzzles")),
+            Ok(Command::Verify(VerifyArgs {
+                input_dir: Some("../puzzles".to_string())
+            }))
+        );
+    }
+
+    #[test]
+    fn parse_bench() {
+        assert_eq!(
+            parse_args(args("bench --all --iterations 5 --save-baseline base.json")),
+            Ok(Command::Bench(BenchArgs {
+                days: DaySelection::All,
+                part: None,
+                warmup: 3,
+                iterations: 5,
+                baseline: None,
+                save_baseline: Some("base.json".to_string()),
+                input_dir: None,
+            }))
+        );
+        assert_eq!(
+            parse_args(args(
+                "bench --day 8 --part 1 --warmup 0 --baseline base.json --input-dir puzzles"
+            )),
+            Ok(Command::Bench(BenchArgs {
+                days: DaySelection::One(8),
+                part: Some(1),
+                warmup: 0,
+                iterations: 20,
+                baseline: Some("base.json".to_string()),
+                save_baseline: None,
+                input_dir: Some("puzzles".to_string()),
+            }))
+        );
+    }
+
+    #[test]
+    fn parse_trace() {
+        let mut params = Params::new();
+        params.set("threshold", "3");
+        assert_eq!(
+            parse_args(args("trace --day 4 --format json --param threshold=3")),
+            Ok(Command::Trace(TraceArgs {
+                day: 4,
+                format: TraceFormat::JsonLines,
+                input: None,
+                params,
+                input_dir: None,
+            }))
+        );
+        assert_eq!(
+            parse_args(args("trace --format yaml --day 4")),
+            Err("--format expected text or json, found 'yaml'".to_string())
+        );
+    }
+
+    #[test]
+    fn parse_new() {
+        assert_eq!(
+            parse_args(args("new --day 10")),
+            Ok(Command::New(NewArgs { day: 10 }))
+        );
+        assert_eq!(
+            parse_args(args("new --day 26")),
+            Err("--day must be between 1 and 25, got 26".to_string())
+        );
+        assert!(parse_args(args("new")).is_err());
+        assert!(parse_args(args("new --day 10 --force")).is_err());
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert!(parse_args(args("")).is_err());
+        assert!(parse_args(args("fly --day 1")).is_err());
+        assert!(parse_args(args("run")).is_err());
+        assert!(parse_args(args("run --day")).is_err());
+        assert!(parse_args(args("run --day eight")).is_err());
+        assert!(parse_args(args("run --day 1 --part 3")).is_err());
+        assert!(parse_args(args("run --day 1 --all")).is_err());
+        assert!(parse_args(args("run --all --input -")).is_err());
+        assert!(parse_args(args("run --day 1 --input - --save")).is_err());
+        assert!(parse_args(args("run --day 1 --param")).is_err());
+        assert!(parse_args(args("run --day 1 --param start")).is_err());
+        assert!(parse_args(args("run --day 1 --param =3")).is_err());
+        assert!(parse_args(args("run --all --param start=3")).is_err());
+        assert!(parse_args(args("run --day 1 --param start=3 --save")).is_err());
+        assert!(parse_args(args("verify --all")).is_err());
+        assert!(parse_args(args("verify --input-dir")).is_err());
+        assert!(parse_args(args("bench --day 1 --iterations 0")).is_err());
+        assert!(parse_args(args("bench --all --baseline")).is_err());
+        assert!(parse_args(args("trace --format text")).is_err());
+        assert!(parse_args(args("trace --day 4 --part 1")).is_err());
+    }
+}
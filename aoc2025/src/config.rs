@@ -0,0 +1,209 @@
+use crate::answers::ANSWERS_FILE;
+use aoc2025::error::AocError;
+use aoc2025::helpers;
+use aoc2025::solution::Params;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+/// The workspace this binary was built from, used when it is run from somewhere else.
+pub const WORKSPACE_ROOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+pub const DEFAULT_YEAR: u32 = 2025;
+
+/// Settings from `aoc.toml`: `key = value` lines at the top, then a `[dayN]` table of
+/// parameters for each day that needs them. Values are quoted strings or bare words.
+///
+/// ```toml
+/// input_dir = "inputs/2025"
+/// year = 2025
+/// answers = "aoc2025/answers.txt"
+///
+/// [day8]
+/// pairs = 1000
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct Config {
+    pub input_dir: Option<PathBuf>,
+    pub year: u32,
+    pub answers: String,
+    days: BTreeMap<u32, Params>,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            input_dir: None,
+            year: DEFAULT_YEAR,
+            answers: ANSWERS_FILE.to_string(),
+            days: BTreeMap::new(),
+        }
+    }
+}
+
+impl Config {
+    /// Loads `aoc.toml` from the current directory, or failing that the workspace root.
+    pub fn find() -> Result<Config, AocError> {
+        let local = Path::new(CONFIG_FILE);
+        if local.exists() {
+            Config::load(local)
+        } else {
+            Config::load(&Path::new(WORKSPACE_ROOT).join(CONFIG_FILE))
+        }
+    }
+
+    /// A missing file gives the default config. Relative paths are taken from the directory
+    /// the file is in.
+    pub fn load(path: &Path) -> Result<Config, AocError> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Config::default()),
+            Err(e) => return Err(AocError::io(&path.display().to_string(), e)),
+        };
+        let mut config = Config::parse(&contents)
+            .map_err(|e| AocError::Invalid(format!("{}, {e}", path.display())))?;
+        let base = path.parent().unwrap_or(Path::new(""));
+        config.input_dir = config.input_dir.map(|dir| base.join(dir));
+        config.answers = base.join(&config.answers).display().to_string();
+        Ok(config)
+    }
+
+    pub fn parse(contents: &str) -> Result<Config, AocError> {
+        let mut config = Config::default();
+        let mut day = None;
+        for (line, text) in contents.lines().enumerate() {
+            let text = text.trim();
+            if text.is_empty() || text.starts_with('#') {
+                continue;
+            }
+            if let Some(table) = text.strip_prefix('[').and_then(|t| t.strip_suffix(']')) {
+                let number = table.strip_prefix("day").ok_or(AocError::malformed(
+                    line,
+                    0,
+                    format!("expected a table like [day1], found '{text}'"),
+                ))?;
+                let number = helpers::parse_number(number, line, 4)?;
+                config.days.entry(number).or_default();
+                day = Some(number);
+                continue;
+            }
+            let Some((key, value)) = text.split_once('=') else {
+                return Err(AocError::malformed(
+                    line,
+                    0,
+                    format!("expected 'key = value', found '{text}'"),
+                ));
+            };
+            let column = text.len() - value.trim_start().len();
+            let (key, value) = (
+                key.trim(),
+                helpers::parse_value(value.trim(), line, column)?,
+            );
+            match (day, key) {
+                (Some(number), _) => config.days.entry(number).or_default().set(key, value),
+                (None, "input_dir") => config.input_dir = Some(PathBuf::from(value)),
+                (None, "year") => config.year = helpers::parse_number(value, line, column)?,
+                (None, "answers") => config.answers = value.to_string(),
+                (None, _) => {
+                    return Err(AocError::malformed(line, 0, format!("unknown key '{key}'")));
+                }
+            }
+        }
+        Ok(config)
+    }
+
+    /// The directory inputs are in when nothing else says otherwise.
+    pub fn default_input_dir(&self) -> String {
+        format!("inputs/{}", self.year)
+    }
+
+    /// The parameters set for `day`, with `overrides` from the command line taking precedence.
+    pub fn params(&self, day: u32, overrides: &Params) -> Params {
+        let mut params = self.days.get(&day).cloned().unwrap_or_default();
+        params.extend(overrides);
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_reads_settings_and_day_tables() {
+        let config = Config::parse(
+            "# inputs\n\ninput_dir = \"puzzles/2025\"\nyear = 2024\n\n\
+             [day8]\npairs = 10\ncircuits = \"2\"\n[day1]\nstart = 0\n",
+        )
+        .unwrap();
+        assert_eq!(config.input_dir, Some(PathBuf::from("puzzles/2025")));
+        assert_eq!(config.year, 2024);
+        assert_eq!(config.default_input_dir(), "inputs/2024");
+        assert_eq!(config.answers, ANSWERS_FILE);
+
+        let mut day8 = Params::new();
+        day8.set("pairs", "10");
+        day8.set("circuits", "2");
+        assert_eq!(config.params(8, &Params::new()), day8);
+
+        let mut overrides = Params::new();
+        overrides.set("pairs", "1000");
+        day8.set("pairs", "1000");
+        assert_eq!(config.params(8, &overrides), day8);
+        assert_eq!(config.params(3, &Params::new()), Params::new());
+        assert_eq!(Config::parse("").unwrap(), Config::default());
+    }
+
+    #[test]
+    fn parse_reports_bad_lines() {
+        assert_eq!(
+            Config::parse("input_dir\n").unwrap_err().to_string(),
+            "line 1, col 1: expected 'key = value', found 'input_dir'"
+        );
+        assert_eq!(
+            Config::parse("input_dir = \"puzzles\n")
+                .unwrap_err()
+                .to_string(),
+            "line 1, col 13: unterminated string \"puzzles"
+        );
+        assert_eq!(
+            Config::parse("\ninput = \"puzzles\"\n")
+                .unwrap_err()
+                .to_string(),
+            "line 2, col 1: unknown key 'input'"
+        );
+        assert_eq!(
+            Config::parse("year = twenty\n").unwrap_err().to_string(),
+            "line 1, col 8: expected a number, found 'twenty'"
+        );
+        assert_eq!(
+            Config::parse("[part1]\n").unwrap_err().to_string(),
+            "line 1, col 1: expected a table like [day1], found '[part1]'"
+        );
+        assert_eq!(
+            Config::parse("[day8]\npairs = 10 20\n")
+                .unwrap_err()
+                .to_string(),
+            "line 2, col 9: expected a value, found '10 20'"
+        );
+    }
+
+    #[test]
+    fn load_is_relative_to_the_file() {
+        let dir = std::env::temp_dir().join(format!("aoc2025-config-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(CONFIG_FILE);
+        fs::write(&path, "input_dir = \"inputs\"\nanswers = \"answers.txt\"\n").unwrap();
+        let config = Config::load(&path).unwrap();
+        assert_eq!(config.input_dir, Some(dir.join("inputs")));
+        assert_eq!(
+            config.answers,
+            dir.join("answers.txt").display().to_string()
+        );
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(Config::load(&path).unwrap(), Config::default());
+    }
+}
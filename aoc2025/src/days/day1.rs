@@ -0,0 +1,353 @@
+//! Day 1: turning a safe's dial through a list of rotations and counting when it points at zero.
+
+use crate::error::AocError;
+use crate::helpers;
+use crate::registry::Day;
+use crate::solution::{Params, Solution};
+use std::fmt::Display;
+
+/// The registry entry for day 1, with the input split into lines.
+pub const DAY: Day = Day::new::<Day1>(1, "\n");
+
+/// Parses and solves day 1.
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = (Vec<i32>, Dial);
+
+    const PARAMS: &'static [&'static str] = &["size", "start"];
+
+    fn parse(lines: Vec<String>) -> Result<Self::Input, AocError> {
+        let movements = lines
+            .iter()
+            .enumerate()
+            .map(|(index, line)| parse_code(line, index))
+            .collect::<Result<_, _>>()?;
+        Ok((movements, Dial::new(SIZE, START)))
+    }
+
+    fn configure((_, dial): &mut Self::Input, params: &Params) -> Result<(), AocError> {
+        let size = params.get_or("size", SIZE)?;
+        if size <= 0 {
+            return Err(AocError::Invalid(format!(
+                "parameter 'size': a dial needs at least one position, found {size}"
+            )));
+        }
+        *dial = Dial::new(size, params.get_or("start", START)?);
+        Ok(())
+    }
+
+    fn part1((movements, dial): &Self::Input) -> Result<Option<impl Display>, AocError> {
+        Ok(Some(get_password(movements, *dial, Counting::LandOnZero)))
+    }
+
+    fn part2((movements, dial): &Self::Input) -> Result<Option<impl Display>, AocError> {
+        Ok(Some(get_password(movements, *dial, Counting::Both)))
+    }
+}
+
+const SIZE: i32 = 100;
+const START: i32 = 50;
+
+/// Which moments of the dial pointing at zero count towards the password.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Counting {
+    /// A rotation that ends on zero.
+    LandOnZero,
+    /// A click onto zero partway through a rotation.
+    PassZero,
+    /// Any click onto zero, partway through or at the end of a rotation.
+    Both,
+}
+
+/// A dial of `size` positions, numbered from 0, and the position it points at.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Dial {
+    size: i32,
+    position: i32,
+}
+
+impl Dial {
+    /// Panics if `size` is not positive. `start` wraps around the dial.
+    pub fn new(size: i32, start: i32) -> Dial {
+        assert!(size > 0, "a dial needs at least one position");
+        Dial {
+            size,
+            position: start.rem_euclid(size),
+        }
+    }
+
+    /// The position after `movement` clicks, and how many of those clicks landed on zero.
+    /// Worked out in `i64`, so no `i32` movement can overflow.
+    fn apply_movement(&self, movement: i32) -> (i32, i64) {
+        let (size, start) = (i64::from(self.size), i64::from(self.position));
+        let movement = i64::from(movement);
+        let final_location = (start + movement).rem_euclid(size);
+        let zero_crossings = if movement > 0 {
+            (movement + start) / size
+        } else if movement.abs() < start {
+            0
+        } else if start == 0 {
+            (movement.abs() - start) / size
+        } else {
+            (movement.abs() - start) / size + 1
+        };
+        let final_location =
+            i32::try_from(final_location).expect("the position is below the dial's i32 size");
+        (final_location, zero_crossings)
+    }
+
+    /// Turns the dial, returning how many times that counts as pointing at zero.
+    fn turn(&mut self, movement: i32, counting: Counting) -> i64 {
+        let (location, zero_crossings) = self.apply_movement(movement);
+        self.position = location;
+        let landed = location == 0;
+        match counting {
+            Counting::LandOnZero => i64::from(landed),
+            Counting::PassZero => zero_crossings - i64::from(landed && movement != 0),
+            Counting::Both => zero_crossings,
+        }
+    }
+}
+
+/// The password after turning `dial` by each movement in turn: how many times it points at
+/// zero, as `counting` decides.
+pub fn get_password(movements: &[i32], dial: Dial, counting: Counting) -> i64 {
+    let dial_locations = dial_locations_and_crossings(dial, movements, counting);
+    dial_locations
+        .iter()
+        .map(|(loc, crossings)| {
+            if *loc < 0 || *loc >= dial.size {
+                panic!("loc was out of range: {loc}")
+            }
+            if *crossings < 0 {
+                panic!("crossings was negative: {crossings}")
+            }
+            crossings
+        })
+        .sum::<i64>()
+}
+
+fn dial_locations_and_crossings(
+    mut dial: Dial,
+    movements: &[i32],
+    counting: Counting,
+) -> Vec<(i32, i64)> {
+    let mut dial_locations = vec![(dial.position, 0)];
+    for &movement in movements {
+        let zero_crossings = dial.turn(movement, counting);
+        dial_locations.push((dial.position, zero_crossings));
+    }
+    dial_locations
+}
+
+/// A movement like `L68` or `R48` as a signed number of clicks, left being negative. `line` is
+/// only used to report errors.
+pub fn parse_code(code: &str, line: usize) -> Result<i32, AocError> {
+    // The direction gives the sign, so the clicks are digits alone, which also keeps `-num`
+    // in range.
+    let clicks = |num: &str| {
+        if num.starts_with(|c: char| c.is_ascii_digit()) {
+            helpers::parse_number::<i32>(num, line, 1)
+        } else {
+            Err(AocError::malformed(
+                line,
+                1,
+                format!("expected a number, found '{num}'"),
+            ))
+        }
+    };
+    match code.to_ascii_lowercase().split_at_checked(1) {
+        Some(("r", num)) => clicks(num),
+        Some(("l", num)) => clicks(num).map(|num| -num),
+        _ => Err(AocError::malformed(
+            line,
+            0,
+            format!("expected a movement like L68 or R48, found '{code}'"),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+
+    #[test]
+    fn parse_code_gives_values() {
+        assert_eq!(parse_code("R32", 0).unwrap(), 32);
+        assert_eq!(parse_code("L500", 0).unwrap(), -500);
+        assert_eq!(parse_code("L10", 0).unwrap(), -10);
+        assert_eq!(parse_code("R0", 0).unwrap(), 0);
+    }
+
+    #[test]
+    fn parse_code_reports_position() {
+        assert_eq!(
+            parse_code("U12", 3).unwrap_err().to_string(),
+            "line 4, col 1: expected a movement like L68 or R48, found 'U12'"
+        );
+        assert_eq!(
+            parse_code("R1x", 0).unwrap_err().to_string(),
+            "line 1, col 2: expected a number, found '1x'"
+        );
+        for code in ["R-5", "L-2147483648", "R+5"] {
+            assert_eq!(
+                parse_code(code, 0).unwrap_err().to_string(),
+                format!("line 1, col 2: expected a number, found '{}'", &code[1..])
+            );
+        }
+        assert_eq!(parse_code("L2147483647", 0).unwrap(), -i32::MAX);
+    }
+
+    #[test]
+    fn turns_near_i32_max_do_not_overflow() {
+        assert_eq!(apply_movement(100, 99, i32::MAX), (46, 21474837));
+        assert_eq!(apply_movement(1, 0, i32::MIN), (0, 1 << 31));
+        let movements = Day1::parse(vec!["R2147483647".to_string(); 3]).unwrap().0;
+        assert_eq!(
+            get_password(&movements, Dial::new(1, 0), Counting::Both),
+            3 * i64::from(i32::MAX)
+        );
+    }
+
+    /// `(start, movement, (end, zero crossings))` on a dial of 100.
+    const MOVEMENTS: [(i32, i32, (i32, i64)); 29] = [
+        (50, -30, (20, 0)),
+        (50, 30, (80, 0)),
+        (50, -60, (90, 1)),
+        // operations from example:
+        (50, -68, (82, 1)),
+        (82, -30, (52, 0)),
+        (52, 48, (0, 1)),
+        (0, -5, (95, 0)),
+        (95, 60, (55, 1)),
+        (55, -55, (0, 1)),
+        (0, -1, (99, 0)),
+        (99, -99, (0, 1)),
+        (0, 14, (14, 0)),
+        (14, -82, (32, 1)),
+        // my examples
+        (90, 9, (99, 0)),
+        (90, 11, (1, 1)),
+        (90, 10, (0, 1)),
+        (90, 110, (0, 2)),
+        (0, 50, (50, 0)),
+        (0, -50, (50, 0)),
+        (50, -20, (30, 0)),
+        (50, -120, (30, 1)),
+        (60, -70, (90, 1)),
+        (10, -10, (0, 1)),
+        (10, -110, (0, 2)),
+        (10, -210, (0, 3)),
+        (50, 1000, (50, 10)),
+        (0, 0, (0, 0)),
+        (0, 100, (0, 1)),
+        (0, -300, (0, 3)),
+    ];
+
+    fn apply_movement(size: i32, start: i32, movement: i32) -> (i32, i64) {
+        Dial::new(size, start).apply_movement(movement)
+    }
+
+    /// Turns the dial one click at a time, counting each click that lands on zero.
+    fn click_by_click(size: i32, start: i32, movement: i32) -> (i32, i64) {
+        let mut location = start;
+        let mut zero_crossings = 0;
+        for _ in 0..movement.abs() {
+            location = (location + movement.signum()).rem_euclid(size);
+            if location == 0 {
+                zero_crossings += 1;
+            }
+        }
+        (location, zero_crossings)
+    }
+
+    #[test]
+    fn apply_movement_test() {
+        for (start, movement, expected) in MOVEMENTS {
+            assert_eq!(
+                apply_movement(100, start, movement),
+                expected,
+                "{start} {movement}"
+            );
+        }
+    }
+
+    #[test]
+    fn apply_movement_table_holds_for_any_dial_size() {
+        for scale in [1, 2, 3, 7, 10] {
+            let size = 100 * scale;
+            for (start, movement, (end, crossings)) in MOVEMENTS {
+                assert_eq!(
+                    apply_movement(size, start * scale, movement * scale),
+                    (end * scale, crossings),
+                    "size {size}: {start} {movement}"
+                );
+            }
+        }
+        for size in 1..=40 {
+            for start in 0..size {
+                for movement in -3 * size..=3 * size {
+                    assert_eq!(
+                        apply_movement(size, start, movement),
+                        click_by_click(size, start, movement),
+                        "size {size}: {start} {movement}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn counting_modes() {
+        let mut dial = Dial::new(10, 5);
+        assert_eq!(dial.turn(15, Counting::LandOnZero), 1);
+        assert_eq!(dial.turn(-25, Counting::PassZero), 2);
+        assert_eq!(dial.position, 5);
+        assert_eq!(dial.turn(25, Counting::Both), 3);
+        assert_eq!(dial.turn(0, Counting::LandOnZero), 1);
+        assert_eq!(dial.turn(0, Counting::PassZero), 0);
+    }
+
+    #[test]
+    fn dial_location_from_lines() {
+        let test_lines = ["R10", "L70", "R110"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let dial_locations = dial_locations_and_crossings(
+            Dial::new(SIZE, START),
+            &Day1::parse(test_lines).unwrap().0,
+            Counting::Both,
+        );
+        assert_eq!(dial_locations, vec![(50, 0), (60, 0), (90, 1), (0, 2)]);
+    }
+
+    #[test]
+    fn get_password_from_lines_problem_example() {
+        let lines = fixtures::example_lines(1, "example");
+        let (movements, dial) = Day1::parse(lines).unwrap();
+        assert_eq!(get_password(&movements, dial, Counting::LandOnZero), 3);
+        assert_eq!(get_password(&movements, dial, Counting::PassZero), 3);
+        assert_eq!(get_password(&movements, dial, Counting::Both), 6);
+    }
+
+    #[test]
+    fn dial_from_params() {
+        let lines = ["R10", "L70"].iter().map(|s| s.to_string()).collect();
+        let mut input = Day1::parse(lines).unwrap();
+        let mut params = Params::new();
+        params.set("start", "60");
+        Day1::configure(&mut input, &params).unwrap();
+        assert_eq!(Day1::part1(&input).unwrap().unwrap().to_string(), "1");
+
+        params.set("size", "0");
+        assert_eq!(
+            Day1::configure(&mut input, &params)
+                .unwrap_err()
+                .to_string(),
+            "parameter 'size': a dial needs at least one position, found 0"
+        );
+    }
+}
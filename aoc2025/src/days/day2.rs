@@ -0,0 +1,464 @@
+//! Day 2: summing the IDs in each range that are made of a repeated block of digits.
+
+use crate::error::AocError;
+use crate::helpers::{self, Arithmetic, BigUint, Number};
+use crate::registry::Day;
+use crate::solution::{Params, Solution};
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+/// The registry entry for day 2, with the input split into comma-separated ranges.
+pub const DAY: Day = Day::new::<Day2>(2, ",");
+
+/// Parses and solves day 2.
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = (Vec<(u128, u128)>, Config);
+
+    const PARAMS: &'static [&'static str] = &["part1_rule", "part2_rule", "arithmetic"];
+
+    fn parse(ids: Vec<String>) -> Result<Self::Input, AocError> {
+        let mut column = 0;
+        let ranges = ids
+            .iter()
+            .map(|e| {
+                let range = range_from_string(e, column);
+                column += e.len() + 1;
+                range
+            })
+            .collect::<Result<_, _>>()?;
+        Ok((ranges, Config::default()))
+    }
+
+    fn configure((_, config): &mut Self::Input, params: &Params) -> Result<(), AocError> {
+        config.part1_rule = params.get_or("part1_rule", config.part1_rule)?;
+        config.part2_rule = params.get_or("part2_rule", config.part2_rule)?;
+        config.arithmetic = params.get_or("arithmetic", config.arithmetic)?;
+        Ok(())
+    }
+
+    fn part1((ranges, config): &Self::Input) -> Result<Option<impl Display>, AocError> {
+        total_invalid_ids(ranges, config.part1_rule, config.arithmetic).map(Some)
+    }
+
+    fn part2((ranges, config): &Self::Input) -> Result<Option<impl Display>, AocError> {
+        total_invalid_ids(ranges, config.part2_rule, config.arithmetic).map(Some)
+    }
+}
+
+/// Which IDs made of a repeated block of digits count as invalid.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Rule {
+    /// A block repeated exactly twice, like `6464`.
+    ExactlyTwice,
+    /// A block repeated two or more times, like `6464` or `646464`.
+    AtLeastTwice,
+    /// A block repeated exactly this many times.
+    RepeatedExactly(u32),
+}
+
+impl FromStr for Rule {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Rule, String> {
+        match s {
+            "exactly-twice" => Ok(Rule::ExactlyTwice),
+            "at-least-twice" => Ok(Rule::AtLeastTwice),
+            _ => match s.strip_prefix("repeated-exactly-").map(str::parse) {
+                Some(Ok(times)) if times >= 2 => Ok(Rule::RepeatedExactly(times)),
+                _ => Err(format!(
+                    "expected exactly-twice, at-least-twice or repeated-exactly-<n> \
+                     with n of at least 2, found '{s}'"
+                )),
+            },
+        }
+    }
+}
+
+impl Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Rule::ExactlyTwice => write!(f, "exactly-twice"),
+            Rule::AtLeastTwice => write!(f, "at-least-twice"),
+            Rule::RepeatedExactly(times) => write!(f, "repeated-exactly-{times}"),
+        }
+    }
+}
+
+/// The rule for each part, and what to total the invalid IDs in.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Config {
+    /// The rule for part 1, `exactly-twice` unless given.
+    pub part1_rule: Rule,
+    /// The rule for part 2, `at-least-twice` unless given.
+    pub part2_rule: Rule,
+    /// Whether to total in `u128` or in big integers.
+    pub arithmetic: Arithmetic,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            part1_rule: Rule::ExactlyTwice,
+            part2_rule: Rule::AtLeastTwice,
+            arithmetic: Arithmetic::Native,
+        }
+    }
+}
+
+/// Reference implementation for the differential tests against [`sum_invalid_ids`].
+#[cfg(test)]
+fn is_invalid_id(id: &str) -> bool {
+    let half_len = id.len() / 2;
+    for l in 1..=half_len {
+        if is_invalid_with_chunk_size(id, l) {
+            return true;
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+fn is_invalid_with_chunk_size(id: &str, size: usize) -> bool {
+    let mut iter = id.as_bytes().chunks(size);
+    let first = iter.next().unwrap();
+    for val in iter {
+        if val != first {
+            return false;
+        }
+    }
+    true
+}
+
+/// The sum of the IDs in all of the inclusive `ranges` that break `rule`, totalled in
+/// `arithmetic`, or an error if it does not fit in `u128` under [`Arithmetic::Native`].
+pub fn total_invalid_ids(
+    ranges: &[(u128, u128)],
+    rule: Rule,
+    arithmetic: Arithmetic,
+) -> Result<String, AocError> {
+    let total = match arithmetic {
+        Arithmetic::Native => sum_invalid_ids::<u128>(ranges, rule).map(|sum| sum.to_string()),
+        Arithmetic::Big => sum_invalid_ids::<BigUint>(ranges, rule).map(|sum| sum.to_string()),
+    };
+    total.ok_or_else(|| helpers::too_large(&format!("the total of invalid IDs under rule {rule}")))
+}
+
+/// [`total_invalid_ids`] as a number of type `N`, or `None` if it does not fit.
+pub fn sum_invalid_ids<N: Number>(ranges: &[(u128, u128)], rule: Rule) -> Option<N> {
+    ranges
+        .iter()
+        .try_fold(N::from(0), |total, &(first, second)| {
+            total.checked_add(sum_invalid_in_range(first, second, rule)?)
+        })
+}
+
+/// Sums the IDs in `first..=second` that break `rule`, without visiting every ID, or `None`
+/// if the sum does not fit in `N`. An ID of `digits` digits built from a block of
+/// `block_len` digits is `block * (10^digits - 1) / (10^block_len - 1)`, so each block length
+/// gives an arithmetic series.
+pub fn sum_invalid_in_range<N: Number>(first: u128, second: u128, rule: Rule) -> Option<N> {
+    let mut sum = N::from(0);
+    for digits in digit_count(first)..=digit_count(second) {
+        let lower = first.max(10u128.pow(digits - 1));
+        let upper = 10u128
+            .checked_pow(digits)
+            .map_or(second, |limit| second.min(limit - 1));
+        if lower > upper {
+            continue;
+        }
+        let invalid = match rule {
+            Rule::ExactlyTwice => sum_repeated_exactly(lower, upper, digits, 2),
+            Rule::RepeatedExactly(times) => sum_repeated_exactly(lower, upper, digits, times),
+            Rule::AtLeastTwice => sum_repeated_at_least_twice(lower, upper, digits),
+        };
+        sum = sum.checked_add(invalid?)?;
+    }
+    Some(sum)
+}
+
+fn sum_repeated_exactly<N: Number>(lower: u128, upper: u128, digits: u32, times: u32) -> Option<N> {
+    if times < 2 || !digits.is_multiple_of(times) {
+        return Some(N::from(0));
+    }
+    sum_repeated_blocks(lower, upper, digits, digits / times)
+}
+
+/// IDs with several block lengths (`111111` repeats `1`, `11` and `111`) are counted once by
+/// inclusion–exclusion over the divisors of `digits`.
+fn sum_repeated_at_least_twice<N: Number>(lower: u128, upper: u128, digits: u32) -> Option<N> {
+    let (mut added, mut removed) = (N::from(0), N::from(0));
+    for block_len in (1..digits).filter(|l| digits.is_multiple_of(*l)) {
+        match mobius(digits / block_len) {
+            1 => {
+                removed =
+                    removed.checked_add(sum_repeated_blocks(lower, upper, digits, block_len)?)?
+            }
+            -1 => {
+                added = added.checked_add(sum_repeated_blocks(lower, upper, digits, block_len)?)?
+            }
+            _ => {}
+        }
+    }
+    added.checked_sub(removed)
+}
+
+fn digit_count(value: u128) -> u32 {
+    value.checked_ilog10().unwrap_or(0) + 1
+}
+
+/// The sum of every `digits`-digit number in `lower..=upper` made of one `block_len`-digit block
+/// repeated. Blocks have at most half the digits of a `u128`, so only the final product can
+/// overflow.
+fn sum_repeated_blocks<N: Number>(
+    lower: u128,
+    upper: u128,
+    digits: u32,
+    block_len: u32,
+) -> Option<N> {
+    let block_limit = 10u128.pow(block_len);
+    let repeat = (1..digits / block_len).fold(1, |repeat, _| repeat * block_limit + 1);
+    let first_block = lower.div_ceil(repeat).max(block_limit / 10);
+    let last_block = (upper / repeat).min(block_limit - 1);
+    if first_block > last_block {
+        return Some(N::from(0));
+    }
+    let (ends, count) = (first_block + last_block, last_block - first_block + 1);
+    let block_sum = if count.is_multiple_of(2) {
+        ends * (count / 2)
+    } else {
+        ends / 2 * count
+    };
+    N::from(block_sum).checked_mul(N::from(repeat))
+}
+
+fn mobius(n: u32) -> i32 {
+    let mut remaining = n;
+    let mut sign = 1;
+    let mut factor = 2;
+    while factor * factor <= remaining {
+        if remaining.is_multiple_of(factor) {
+            remaining /= factor;
+            if remaining.is_multiple_of(factor) {
+                return 0;
+            }
+            sign = -sign;
+        }
+        factor += 1;
+    }
+    if remaining > 1 { -sign } else { sign }
+}
+
+fn range_from_string(e: &str, column: usize) -> Result<(u128, u128), AocError> {
+    let Some((first, second)) = e.split_once("-") else {
+        return Err(AocError::malformed(
+            0,
+            column,
+            format!("expected a range like 11-22, found '{e}'"),
+        ));
+    };
+    let second_column = column + first.len() + 1;
+    Ok((
+        helpers::parse_number(first, 0, column)?,
+        helpers::parse_number(second, 0, second_column)?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use std::collections::HashSet;
+
+    #[test]
+    fn id_is_valid_test() {
+        assert!(is_invalid_id("11"));
+        assert!(is_invalid_id("22"));
+        assert!(is_invalid_id("99"));
+        assert!(!is_invalid_id("998"));
+        assert!(!is_invalid_id("1012"));
+        assert!(!is_invalid_id("1188511880"));
+        assert!(is_invalid_id("1188511885"));
+
+        assert!(is_invalid_id("111"));
+    }
+
+    fn example_ranges() -> Vec<(u128, u128)> {
+        Day2::parse(fixtures::example_lines(2, "example"))
+            .unwrap()
+            .0
+    }
+
+    #[test]
+    fn sum_invalid_ids_test() {
+        let sum = sum_invalid_ids::<u128>(&example_ranges(), Rule::AtLeastTwice);
+        assert_eq!(sum, Some(4174379265));
+    }
+
+    #[test]
+    fn other_rules_on_the_example() {
+        let ranges = example_ranges();
+        assert_eq!(
+            sum_invalid_ids::<u128>(&ranges, Rule::RepeatedExactly(2)),
+            Some(1227775554)
+        );
+        assert_eq!(
+            total_invalid_ids(&ranges, Rule::AtLeastTwice, Arithmetic::Big).unwrap(),
+            "4174379265"
+        );
+        assert_eq!(
+            sum_invalid_ids::<u128>(&ranges, Rule::RepeatedExactly(3)),
+            Some(111 + 999 + 222222 + 565656 + 824824824)
+        );
+    }
+
+    #[test]
+    fn bounds_up_to_u128_max_need_big_arithmetic() {
+        let ranges = [(1, 10u128.pow(38) - 1)];
+        assert_eq!(sum_invalid_ids::<u128>(&ranges, Rule::ExactlyTwice), None);
+        assert_eq!(
+            total_invalid_ids(&ranges, Rule::ExactlyTwice, Arithmetic::Big).unwrap(),
+            "495495495495495495500040950040950040945040950040950040950"
+        );
+        let ranges = [(10u128.pow(38), u128::MAX)];
+        assert_eq!(
+            total_invalid_ids(&ranges, Rule::AtLeastTwice, Arithmetic::Big).unwrap(),
+            "528960446238789196028429238379062302382768405243459"
+        );
+        assert_eq!(
+            sum_invalid_ids::<BigUint>(&ranges, Rule::ExactlyTwice),
+            Some(BigUint::zero())
+        );
+
+        let mut input = Day2::parse(vec![format!("1-{}", u128::MAX)]).unwrap();
+        Day2::configure(&mut input, &Params::new()).unwrap();
+        assert_eq!(
+            Day2::part1(&input).err().unwrap().to_string(),
+            "the total of invalid IDs under rule exactly-twice does not fit in 128 bits, \
+             try arithmetic=big"
+        );
+        let mut params = Params::new();
+        params.set("arithmetic", "big");
+        Day2::configure(&mut input, &params).unwrap();
+        assert!(Day2::part1(&input).is_ok());
+    }
+
+    #[test]
+    fn config_from_params() {
+        let mut params = Params::new();
+        params.set("part1_rule", "repeated-exactly-3");
+        params.set("arithmetic", "big");
+        let mut input = (example_ranges(), Config::default());
+        Day2::configure(&mut input, &params).unwrap();
+        assert_eq!(
+            input.1,
+            Config {
+                part1_rule: Rule::RepeatedExactly(3),
+                part2_rule: Rule::AtLeastTwice,
+                arithmetic: Arithmetic::Big,
+            }
+        );
+
+        for rule in [
+            Rule::ExactlyTwice,
+            Rule::AtLeastTwice,
+            Rule::RepeatedExactly(5),
+        ] {
+            assert_eq!(rule.to_string().parse(), Ok(rule));
+        }
+        assert!("repeated-exactly-1".parse::<Rule>().is_err());
+        assert!("twice".parse::<Rule>().is_err());
+    }
+
+    /// The rules in terms of `is_invalid_with_chunk_size`.
+    fn is_invalid_by_rule(id: &str, rule: Rule) -> bool {
+        let times = match rule {
+            Rule::AtLeastTwice => return is_invalid_id(id),
+            Rule::ExactlyTwice => 2,
+            Rule::RepeatedExactly(times) => times as usize,
+        };
+        id.len().is_multiple_of(times) && is_invalid_with_chunk_size(id, id.len() / times)
+    }
+
+    fn sum_by_checking_every_id(first: u128, second: u128, rule: Rule) -> u128 {
+        (first..=second)
+            .filter(|id| is_invalid_by_rule(&id.to_string(), rule))
+            .sum()
+    }
+
+    #[test]
+    fn arithmetic_sum_matches_checking_every_id() {
+        let rules = [
+            Rule::ExactlyTwice,
+            Rule::AtLeastTwice,
+            Rule::RepeatedExactly(3),
+            Rule::RepeatedExactly(6),
+        ];
+        for rule in rules {
+            for (first, second) in [(1, 100_000), (95, 115), (998, 1012), (999_990, 1_000_200)] {
+                assert_eq!(
+                    sum_invalid_in_range(first, second, rule),
+                    Some(sum_by_checking_every_id(first, second, rule)),
+                    "{first}-{second} {rule}"
+                );
+            }
+        }
+        for start in (0..1_000_000).step_by(99_991) {
+            assert_eq!(
+                sum_invalid_in_range(start, start + 50_000, Rule::AtLeastTwice),
+                Some(sum_by_checking_every_id(
+                    start,
+                    start + 50_000,
+                    Rule::AtLeastTwice
+                ))
+            );
+        }
+    }
+
+    /// Every ID of up to `max_digits` digits made of a repeated block, built block by block.
+    fn repeated_ids(max_digits: u32) -> HashSet<u128> {
+        let mut ids = HashSet::new();
+        for block_len in 1..=max_digits / 2 {
+            for block in 10u128.pow(block_len - 1)..10u128.pow(block_len) {
+                let mut id = block.to_string().repeat(2);
+                while id.len() <= max_digits as usize {
+                    ids.insert(id.parse().unwrap());
+                    id.push_str(&block.to_string());
+                }
+            }
+        }
+        ids
+    }
+
+    #[test]
+    fn wide_ranges_match_building_every_repeated_id() {
+        let ids = repeated_ids(10);
+        for (first, second) in [
+            (1, 9_999_999_999),
+            (123_456_789, 9_876_543_210),
+            (1_188_511_880, 1_188_511_890),
+        ] {
+            let expected: u128 = ids.iter().filter(|id| (first..=second).contains(*id)).sum();
+            assert_eq!(
+                sum_invalid_in_range(first, second, Rule::AtLeastTwice),
+                Some(expected)
+            );
+        }
+    }
+
+    #[test]
+    fn mobius_test() {
+        let values: Vec<i32> = (1..=10).map(mobius).collect();
+        assert_eq!(values, vec![1, -1, -1, 0, -1, 1, -1, 0, 0, 1]);
+    }
+
+    #[test]
+    fn parse_reports_column_of_bad_range() {
+        let ids = ["11-22", "95-1x5"].iter().map(|e| e.to_string()).collect();
+        assert_eq!(
+            Day2::parse(ids).unwrap_err().to_string(),
+            "line 1, col 10: expected a number, found '1x5'"
+        );
+    }
+}
@@ -0,0 +1,252 @@
+//! Day 3: turning on batteries in each bank to make the largest joltage.
+
+use crate::error::AocError;
+use crate::helpers::{self, Arithmetic, BigUint, Number};
+use crate::registry::Day;
+use crate::solution::{Params, Solution};
+use std::fmt::Display;
+
+/// The registry entry for day 3, with the input split into lines.
+pub const DAY: Day = Day::new::<Day3>(3, "\n");
+
+/// Parses and solves day 3.
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = (Vec<Vec<u8>>, Config);
+
+    const PARAMS: &'static [&'static str] = &["part1_digits", "part2_digits", "arithmetic"];
+
+    fn parse(bank_strings: Vec<String>) -> Result<Self::Input, AocError> {
+        let banks = bank_strings
+            .iter()
+            .enumerate()
+            .map(|(line, str)| bank_powers(str, line))
+            .collect::<Result<_, _>>()?;
+        Ok((banks, Config::default()))
+    }
+
+    fn configure((_, config): &mut Self::Input, params: &Params) -> Result<(), AocError> {
+        config.part1_digits = params.get_or("part1_digits", config.part1_digits)?;
+        config.part2_digits = params.get_or("part2_digits", config.part2_digits)?;
+        config.arithmetic = params.get_or("arithmetic", config.arithmetic)?;
+        Ok(())
+    }
+
+    fn part1((banks, config): &Self::Input) -> Result<Option<impl Display>, AocError> {
+        total_joltage(banks, config.part1_digits, config.arithmetic).map(Some)
+    }
+
+    fn part2((banks, config): &Self::Input) -> Result<Option<impl Display>, AocError> {
+        total_joltage(banks, config.part2_digits, config.arithmetic).map(Some)
+    }
+}
+
+/// How many batteries to turn on in each bank, and what to total the joltages in.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Config {
+    /// Batteries per bank for part 1.
+    pub part1_digits: usize,
+    /// Batteries per bank for part 2.
+    pub part2_digits: usize,
+    /// Whether to total in `u128` or in big integers.
+    pub arithmetic: Arithmetic,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            part1_digits: 2,
+            part2_digits: 12,
+            arithmetic: Arithmetic::Native,
+        }
+    }
+}
+
+/// The total of the largest joltage each bank can make from `digits_to_use` batteries, in
+/// `arithmetic`, or an error if a bank is too short or the total does not fit.
+pub fn total_joltage(
+    banks: &[Vec<u8>],
+    digits_to_use: usize,
+    arithmetic: Arithmetic,
+) -> Result<String, AocError> {
+    match arithmetic {
+        Arithmetic::Native => {
+            sum_power_banks::<u128>(banks, digits_to_use).map(|total| total.to_string())
+        }
+        Arithmetic::Big => {
+            sum_power_banks::<BigUint>(banks, digits_to_use).map(|total| total.to_string())
+        }
+    }
+}
+
+/// [`total_joltage`] as a number of type `N`.
+pub fn sum_power_banks<N: Number>(banks: &[Vec<u8>], digits_to_use: usize) -> Result<N, AocError> {
+    let too_large = || {
+        helpers::too_large(&format!(
+            "the total of {} {digits_to_use}-digit joltages",
+            banks.len()
+        ))
+    };
+    banks
+        .iter()
+        .enumerate()
+        .try_fold(N::from(0), |total, (line, powers)| {
+            let digits = max_joltage_of_bank(powers, digits_to_use).map_err(|_| {
+                AocError::malformed(
+                    line,
+                    powers.len(),
+                    format!(
+                        "expected at least {digits_to_use} digits, found {}",
+                        powers.len()
+                    ),
+                )
+            })?;
+            values_from_digits::<N>(&digits)
+                .and_then(|value| total.checked_add(value))
+                .ok_or_else(too_large)
+        })
+}
+
+/// The digits of one line of batteries. `line` is only used to report errors.
+pub fn bank_powers(bank_string: &str, line: usize) -> Result<Vec<u8>, AocError> {
+    bank_string
+        .chars()
+        .enumerate()
+        .map(|(column, c)| {
+            c.to_digit(10).map(|d| d as u8).ok_or(AocError::malformed(
+                line,
+                column,
+                format!("expected a digit, found '{c}'"),
+            ))
+        })
+        .collect()
+}
+
+/// The largest number made by keeping `digits_to_use` of the bank's digits in order, as its
+/// digits. A digit is dropped whenever a larger one follows it and there are still digits to
+/// spare, which leaves the kept digits as a non-increasing stack apart from the tail.
+pub fn max_joltage_of_bank(powers: &[u8], digits_to_use: usize) -> Result<Vec<u8>, AocError> {
+    let Some(mut to_drop) = powers.len().checked_sub(digits_to_use) else {
+        return Err(AocError::Invalid(format!(
+            "bank has {} digits but {digits_to_use} are needed",
+            powers.len()
+        )));
+    };
+    let mut kept: Vec<u8> = Vec::with_capacity(powers.len());
+    for &power in powers {
+        while to_drop > 0 && kept.last().is_some_and(|&last| last < power) {
+            kept.pop();
+            to_drop -= 1;
+        }
+        kept.push(power);
+    }
+    kept.truncate(digits_to_use);
+    Ok(kept)
+}
+
+fn values_from_digits<N: Number>(digits: &[u8]) -> Option<N> {
+    digits.iter().try_fold(N::from(0), |acc, &digit| {
+        acc.checked_mul(N::from(10))?
+            .checked_add(N::from(u128::from(digit)))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+
+    fn joltage(bank: &str, digits_to_use: usize) -> u128 {
+        values_from_digits(
+            &max_joltage_of_bank(&bank_powers(bank, 0).unwrap(), digits_to_use).unwrap(),
+        )
+        .unwrap()
+    }
+
+    fn example_banks() -> Vec<Vec<u8>> {
+        Day3::parse(fixtures::example_lines(3, "example"))
+            .unwrap()
+            .0
+    }
+
+    #[test]
+    fn bank_joltage_examples() {
+        assert_eq!(joltage("987654321111111", 12), 987654321111);
+        assert_eq!(joltage("811111111111119", 12), 811111111119);
+        assert_eq!(joltage("234234234234278", 12), 434234234278);
+        assert_eq!(joltage("818181911112111", 12), 888911112111);
+
+        assert_eq!(joltage("987654321111111", 2), 98);
+        assert_eq!(joltage("811111111111119", 2), 89);
+        assert_eq!(joltage("234234234234278", 2), 78);
+        assert_eq!(joltage("818181911112111", 2), 92);
+    }
+
+    #[test]
+    fn zeros_short_banks_and_long_banks() {
+        assert_eq!(joltage("0000", 2), 0);
+        assert_eq!(joltage("1000", 3), 100);
+        assert_eq!(joltage("42", 2), 42);
+        assert_eq!(joltage("42", 0), 0);
+        assert_eq!(
+            max_joltage_of_bank(&[4, 2], 3).unwrap_err().to_string(),
+            "bank has 2 digits but 3 are needed"
+        );
+
+        let long_bank: Vec<u8> = (0..10_000).map(|i| (i * 7 % 10) as u8).collect();
+        let digits = max_joltage_of_bank(&long_bank, 60).unwrap();
+        assert_eq!(digits.len(), 60);
+        assert!(digits[..59].iter().all(|&d| d == 9));
+    }
+
+    #[test]
+    fn config_from_params() {
+        let mut input = (example_banks(), Config::default());
+        let mut params = Params::new();
+        params.set("part1_digits", "3");
+        Day3::configure(&mut input, &params).unwrap();
+        assert_eq!(
+            sum_power_banks::<u128>(&input.0, input.1.part1_digits).unwrap(),
+            987 + 819 + 478 + 921
+        );
+
+        params.set("part2_digits", "16");
+        Day3::configure(&mut input, &params).unwrap();
+        assert_eq!(
+            Day3::part2(&input).err().unwrap().to_string(),
+            "line 1, col 16: expected at least 16 digits, found 15"
+        );
+        let long_banks = vec![vec![9; 50]; 3];
+        assert_eq!(
+            total_joltage(&long_banks, 39, Arithmetic::Native)
+                .unwrap_err()
+                .to_string(),
+            "the total of 3 39-digit joltages does not fit in 128 bits, try arithmetic=big"
+        );
+
+        params.set("part2_digits", "40");
+        params.set("arithmetic", "big");
+        let mut input = (long_banks, Config::default());
+        Day3::configure(&mut input, &params).unwrap();
+        assert_eq!(
+            total_joltage(&input.0, input.1.part2_digits, input.1.arithmetic).unwrap(),
+            format!("2{}7", "9".repeat(39))
+        );
+    }
+
+    #[test]
+    fn a_short_bank_is_an_error_from_the_part() {
+        let input = Day3::parse(vec!["987".to_string(), "1".to_string()]).unwrap();
+        assert_eq!(
+            Day3::part1(&input).err().unwrap().to_string(),
+            "line 2, col 2: expected at least 2 digits, found 1"
+        );
+    }
+
+    #[test]
+    fn value_from_digits_vec_test() {
+        let digits = vec![1, 2, 3, 4];
+        assert_eq!(values_from_digits::<u128>(&digits), Some(1234))
+    }
+}
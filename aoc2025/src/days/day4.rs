@@ -0,0 +1,426 @@
+//! Day 4: removing the rolls of paper that forklifts can reach, wave after wave.
+
+use crate::error::AocError;
+use crate::helpers::{Grid, Position};
+use crate::json::Json;
+use crate::registry::Day;
+use crate::solution::{Params, Solution, TraceFormat};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// The registry entry for day 4, with the input split into lines.
+pub const DAY: Day = Day::new::<Day4>(4, "\n");
+
+/// Parses and solves day 4.
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = (Grid<bool>, Rules);
+
+    const PARAMS: &'static [&'static str] = &["threshold", "neighbourhood"];
+
+    fn parse(lines: Vec<String>) -> Result<Self::Input, AocError> {
+        Ok((to_bool_vec(lines)?, Rules::default()))
+    }
+
+    fn configure((_, rules): &mut Self::Input, params: &Params) -> Result<(), AocError> {
+        rules.threshold = params.get_or("threshold", rules.threshold)?;
+        rules.neighbourhood = params.get_or("neighbourhood", rules.neighbourhood)?;
+        Ok(())
+    }
+
+    fn part1((locations, rules): &Self::Input) -> Result<Option<impl Display>, AocError> {
+        Ok(Some(removed_in_first_wave(&removal_waves(
+            locations, *rules,
+        ))))
+    }
+
+    fn part2((locations, rules): &Self::Input) -> Result<Option<impl Display>, AocError> {
+        Ok(Some(total_removed(&removal_waves(locations, *rules))))
+    }
+
+    fn trace((locations, rules): &Self::Input, format: TraceFormat) -> Option<String> {
+        let waves = removal_waves(locations, *rules);
+        Some(match format {
+            TraceFormat::Text => render_waves(&waves, locations),
+            TraceFormat::JsonLines => waves_to_json_lines(&waves),
+        })
+    }
+}
+
+/// Which cells count as a roll's neighbours.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Neighbourhood {
+    /// The 4 cells sharing an edge, given as `4`.
+    Orthogonal,
+    /// The 8 cells sharing an edge or a corner, given as `8`.
+    Surrounding,
+}
+
+impl Neighbourhood {
+    fn neighbors<T>(
+        self,
+        grid: &Grid<T>,
+        position: Position,
+    ) -> impl Iterator<Item = Position> + '_ {
+        grid.neighbors8(position).filter(move |&(x, y)| {
+            self == Neighbourhood::Surrounding || x == position.0 || y == position.1
+        })
+    }
+}
+
+impl FromStr for Neighbourhood {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Neighbourhood, String> {
+        match s {
+            "4" => Ok(Neighbourhood::Orthogonal),
+            "8" => Ok(Neighbourhood::Surrounding),
+            _ => Err(format!("expected 4 or 8, found '{s}'")),
+        }
+    }
+}
+
+/// A roll can be removed while fewer than `threshold` of its neighbours are rolls.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rules {
+    /// How many neighbouring rolls stop a roll being removed.
+    pub threshold: usize,
+    /// Which cells are neighbours.
+    pub neighbourhood: Neighbourhood,
+}
+
+impl Default for Rules {
+    fn default() -> Rules {
+        Rules {
+            threshold: 4,
+            neighbourhood: Neighbourhood::Surrounding,
+        }
+    }
+}
+
+/// The wave, counting from 1, in which each roll gets removed, or `None` for cells that are
+/// empty or never removed. Each roll keeps a count of its neighbouring rolls, and only the
+/// neighbours of rolls removed in one wave are looked at for the next, so the cost is linear
+/// in the size of the grid however many waves there are.
+pub fn removal_waves(locations: &Grid<bool>, rules: Rules) -> Grid<Option<usize>> {
+    let mut present = locations.clone();
+    let mut counts = locations.map(|_| 0);
+    for point in locations.positions().filter(|p| locations[*p]) {
+        counts[point] = rules
+            .neighbourhood
+            .neighbors(locations, point)
+            .filter(|p| locations[*p])
+            .count();
+    }
+
+    let mut waves = locations.map(|_| None);
+    let mut current: Vec<Position> = locations
+        .positions()
+        .filter(|p| locations[*p] && counts[*p] < rules.threshold)
+        .collect();
+    let mut wave = 1;
+    while !current.is_empty() {
+        for &point in &current {
+            present[point] = false;
+            waves[point] = Some(wave);
+        }
+        let mut next = Vec::new();
+        for &point in &current {
+            for neighbor in rules.neighbourhood.neighbors(locations, point) {
+                if !present[neighbor] {
+                    continue;
+                }
+                counts[neighbor] -= 1;
+                // Rolls still present had at least `threshold` neighbours, so each one drops
+                // below it exactly once.
+                if counts[neighbor] + 1 == rules.threshold {
+                    next.push(neighbor);
+                }
+            }
+        }
+        current = next;
+        wave += 1;
+    }
+    waves
+}
+
+/// How many rolls can be removed straight away.
+pub fn removed_in_first_wave(waves: &Grid<Option<usize>>) -> usize {
+    waves.positions().filter(|p| waves[*p] == Some(1)).count()
+}
+
+/// How many rolls are removed by the time no more can be.
+pub fn total_removed(waves: &Grid<Option<usize>>) -> usize {
+    waves.positions().filter(|p| waves[*p].is_some()).count()
+}
+
+/// The points removed in each wave, in reading order.
+pub fn points_by_wave(waves: &Grid<Option<usize>>) -> Vec<Vec<Position>> {
+    let mut rounds: Vec<Vec<Position>> = Vec::new();
+    for point in waves.positions() {
+        if let Some(wave) = waves[point] {
+            if rounds.len() < wave {
+                rounds.resize(wave, Vec::new());
+            }
+            rounds[wave - 1].push(point);
+        }
+    }
+    rounds
+}
+
+/// Draws the grid as it stands at the start of each round, with the rolls removed in that
+/// round marked `x`.
+pub fn render_waves(waves: &Grid<Option<usize>>, locations: &Grid<bool>) -> String {
+    let rounds = points_by_wave(waves);
+    let mut text = String::new();
+    for (index, removed) in rounds.iter().enumerate() {
+        let round = index + 1;
+        let grid = locations
+            .positions()
+            .fold(locations.map(|_| '.'), |mut grid, point| {
+                grid[point] = match waves[point] {
+                    Some(wave) if wave == round => 'x',
+                    Some(wave) if wave < round => '.',
+                    _ if locations[point] => '@',
+                    _ => '.',
+                };
+                grid
+            });
+        text.push_str(&format!(
+            "round {round}: removed {}\n{grid}\n\n",
+            removed.len()
+        ));
+    }
+    text
+}
+
+/// One line per round, like `{"round":1,"removed":[[2,0],[3,0]]}`.
+pub fn waves_to_json_lines(waves: &Grid<Option<usize>>) -> String {
+    points_by_wave(waves)
+        .into_iter()
+        .enumerate()
+        .map(|(index, removed)| {
+            let removed = removed
+                .into_iter()
+                .map(|(x, y)| Json::Array(vec![Json::Number(x as f64), Json::Number(y as f64)]))
+                .collect();
+            let round = Json::Object(vec![
+                ("round".to_string(), Json::Number((index + 1) as f64)),
+                ("removed".to_string(), Json::Array(removed)),
+            ]);
+            format!("{round}\n")
+        })
+        .collect()
+}
+
+/// The reference implementation for the differential tests against [`removal_waves`]: every
+/// roll with fewer than four neighbouring rolls, found by scanning the whole grid.
+#[cfg(test)]
+fn count_removable_points(locations: &Grid<bool>) -> Vec<Position> {
+    let mut removed_points = Vec::new();
+
+    for point in locations.positions() {
+        if !locations[point] {
+            continue;
+        }
+
+        let count = locations
+            .neighbors8(point)
+            .filter(|p| locations[*p])
+            .count();
+        if count < 4 {
+            removed_points.push(point);
+        }
+    }
+    removed_points
+}
+
+/// How many rolls [`count_removable_points`] takes away, rescanning until none are left to take.
+#[cfg(test)]
+fn count_total_removed(locations: &Grid<bool>) -> i32 {
+    let mut locations = locations.clone();
+    let mut total_removed = 0;
+    loop {
+        let points_removed = count_removable_points(&locations);
+        total_removed += points_removed.len();
+        if points_removed.is_empty() {
+            break;
+        }
+        clear_locations(&mut locations, points_removed);
+    }
+
+    total_removed as i32
+}
+
+#[cfg(test)]
+fn clear_locations(locations: &mut Grid<bool>, points: Vec<Position>) {
+    for point in points {
+        locations[point] = false;
+    }
+}
+
+/// The grid of rolls, `@` marking a roll and `.` an empty cell.
+pub fn to_bool_vec(location_strings: Vec<String>) -> Result<Grid<bool>, AocError> {
+    Grid::parse(&location_strings, |c| match c {
+        '@' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+
+    fn example_data() -> Vec<String> {
+        fixtures::example_lines(4, "example")
+    }
+
+    #[test]
+    fn test_example_problem() {
+        let bool_vec = to_bool_vec(example_data()).unwrap();
+        let removed_points = count_removable_points(&bool_vec);
+
+        assert_eq!(13, removed_points.len())
+    }
+
+    #[test]
+    fn test_part_2_example() {
+        let count = count_total_removed(&to_bool_vec(example_data()).unwrap());
+        assert_eq!(count, 43);
+    }
+
+    #[test]
+    fn removal_waves_match_the_example() {
+        let waves = removal_waves(&to_bool_vec(example_data()).unwrap(), Rules::default());
+        assert_eq!(removed_in_first_wave(&waves), 13);
+        assert_eq!(total_removed(&waves), 43);
+        assert_eq!(waves[(2, 0)], Some(1));
+        assert_eq!(waves[(0, 0)], None);
+    }
+
+    #[test]
+    fn removal_waves_match_removing_round_by_round() {
+        let mut state: u64 = 7;
+        for _ in 0..20 {
+            let cells = (0..30 * 20)
+                .map(|_| {
+                    state = state
+                        .wrapping_mul(6364136223846793005)
+                        .wrapping_add(1442695040888963407);
+                    (state >> 33) % 10 < 7
+                })
+                .collect();
+            let mut locations = Grid::new(30, 20, cells);
+            let waves = removal_waves(&locations, Rules::default());
+            assert_eq!(
+                total_removed(&waves),
+                count_total_removed(&locations) as usize
+            );
+
+            let mut wave = 1;
+            loop {
+                let removed = count_removable_points(&locations);
+                if removed.is_empty() {
+                    break;
+                }
+                for &point in &removed {
+                    assert_eq!(waves[point], Some(wave));
+                }
+                clear_locations(&mut locations, removed);
+                wave += 1;
+            }
+        }
+    }
+
+    #[test]
+    fn rules_from_params() {
+        let mut input = Day4::parse(example_data()).unwrap();
+        let mut params = Params::new();
+        params.set("threshold", "0");
+        Day4::configure(&mut input, &params).unwrap();
+        assert_eq!(Day4::part2(&input).unwrap().unwrap().to_string(), "0");
+
+        // A plus shape: with orthogonal neighbours and a threshold of 2 only the arms go,
+        // after which the centre has none left.
+        let plus = [".@.", "@@@", ".@."]
+            .iter()
+            .map(|e| e.to_string())
+            .collect();
+        let mut input = Day4::parse(plus).unwrap();
+        params.set("threshold", "2");
+        params.set("neighbourhood", "4");
+        Day4::configure(&mut input, &params).unwrap();
+        let waves = removal_waves(&input.0, input.1);
+        assert_eq!(waves[(1, 0)], Some(1));
+        assert_eq!(waves[(1, 1)], Some(2));
+
+        params.set("neighbourhood", "6");
+        assert_eq!(
+            Day4::configure(&mut input, &params)
+                .unwrap_err()
+                .to_string(),
+            "parameter 'neighbourhood': expected 4 or 8, found '6'"
+        );
+    }
+
+    #[test]
+    fn trace_marks_each_round() {
+        let paper = ["@@@", "@@@", "@.@"]
+            .iter()
+            .map(|e| e.to_string())
+            .collect();
+        let input = Day4::parse(paper).unwrap();
+        assert_eq!(
+            Day4::trace(&input, TraceFormat::Text).unwrap(),
+            "round 1: removed 4\nx@x\n@@@\nx.x\n\nround 2: removed 4\n.x.\nxxx\n...\n\n"
+        );
+        assert_eq!(
+            Day4::trace(&input, TraceFormat::JsonLines).unwrap(),
+            "{\"round\":1,\"removed\":[[0,0],[2,0],[0,2],[2,2]]}\n\
+             {\"round\":2,\"removed\":[[1,0],[0,1],[1,1],[2,1]]}\n"
+        );
+
+        let input = Day4::parse(example_data()).unwrap();
+        let lines: Vec<Json> = Day4::trace(&input, TraceFormat::JsonLines)
+            .unwrap()
+            .lines()
+            .map(|line| Json::parse(line).unwrap())
+            .collect();
+        let sizes: Vec<usize> = lines
+            .iter()
+            .map(|round| round.get("removed").unwrap().as_array().unwrap().len())
+            .collect();
+        assert_eq!(sizes[0], 13);
+        assert_eq!(sizes.iter().sum::<usize>(), 43);
+    }
+
+    #[test]
+    fn string_vec_to_bool_vec() {
+        let paper = [".@", "@."].iter().map(|e| e.to_string()).collect();
+
+        let bool_vec = to_bool_vec(paper).unwrap();
+        assert_eq!(bool_vec, Grid::new(2, 2, vec![false, true, true, false]));
+        assert_eq!(
+            bool_vec.map(|p| if *p { '@' } else { '.' }).to_string(),
+            ".@\n@."
+        );
+    }
+
+    #[test]
+    fn to_bool_vec_rejects_bad_cells_and_ragged_rows() {
+        let paper = [".@", "@x"].iter().map(|e| e.to_string()).collect();
+        assert_eq!(
+            to_bool_vec(paper).unwrap_err().to_string(),
+            "line 2, col 2: unexpected character 'x'"
+        );
+
+        let paper = [".@.", "@."].iter().map(|e| e.to_string()).collect();
+        assert_eq!(
+            to_bool_vec(paper).unwrap_err().to_string(),
+            "line 2, col 3: expected 3 cells, found 2"
+        );
+    }
+}
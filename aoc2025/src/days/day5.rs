@@ -0,0 +1,122 @@
+//! Day 5: checking ingredient IDs against the ranges of fresh ones.
+
+use crate::error::AocError;
+use crate::helpers;
+pub use crate::interval_set::{IntervalSet, Range};
+use crate::registry::Day;
+use crate::solution::Solution;
+use std::fmt::Display;
+
+/// The registry entry for day 5, with the input split into lines.
+pub const DAY: Day = Day::new::<Day5>(5, "\n");
+
+/// Parses and solves day 5.
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = (IntervalSet, Vec<u64>);
+
+    fn parse(lines: Vec<String>) -> Result<Self::Input, AocError> {
+        let ids = get_ids_from_lines(&lines)?;
+        let fresh = IntervalSet::merge(&get_ranges_from_lines(lines)?);
+        Ok((fresh, ids))
+    }
+
+    fn part1((fresh, ids): &Self::Input) -> Result<Option<impl Display>, AocError> {
+        Ok(Some(fresh_available_ids(fresh, ids)))
+    }
+
+    fn part2((fresh, _): &Self::Input) -> Result<Option<impl Display>, AocError> {
+        Ok(Some(fresh.size()))
+    }
+}
+
+/// The ranges of fresh IDs, one like `3-5` per line up to the first blank line.
+pub fn get_ranges_from_lines(lines: Vec<String>) -> Result<Vec<Range>, AocError> {
+    lines
+        .iter()
+        .take_while(|s| !s.is_empty())
+        .enumerate()
+        .map(|(line, s)| {
+            let Some((lower_text, upper_text)) = s.split_once("-") else {
+                return Err(AocError::malformed(
+                    line,
+                    0,
+                    format!("expected a range like 3-5, found '{s}'"),
+                ));
+            };
+            let lower = helpers::parse_number(lower_text, line, 0)?;
+            let upper = helpers::parse_number(upper_text, line, lower_text.len() + 1)?;
+            if lower > upper {
+                return Err(AocError::malformed(
+                    line,
+                    0,
+                    format!("range starts at {lower} but ends at {upper}"),
+                ));
+            }
+            Ok(Range::new(lower, upper))
+        })
+        .collect()
+}
+
+/// The available IDs, one per line after the first blank line.
+pub fn get_ids_from_lines(lines: &[String]) -> Result<Vec<u64>, AocError> {
+    let first_id_line = lines
+        .iter()
+        .position(|s| s.is_empty())
+        .map_or(lines.len(), |i| i + 1);
+    lines
+        .iter()
+        .enumerate()
+        .skip(first_id_line)
+        .map(|(line, s)| helpers::parse_number(s, line, 0))
+        .collect()
+}
+
+/// How many of `ids` fall in a fresh range.
+pub fn fresh_available_ids(fresh: &IntervalSet, ids: &[u64]) -> usize {
+    ids.iter().filter(|id| fresh.contains(**id)).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+
+    fn example_lines() -> Vec<String> {
+        fixtures::example_lines(5, "example")
+    }
+
+    #[test]
+    fn example_test() {
+        let (fresh, ids) = Day5::parse(example_lines()).unwrap();
+        assert_eq!(ids, vec![1, 5, 8, 11, 17, 32]);
+        assert_eq!(fresh_available_ids(&fresh, &ids), 3);
+        assert_eq!(fresh.size(), 14);
+    }
+
+    #[test]
+    fn get_ranges_from_lines_reports_bad_ranges() {
+        let lines = ["3-5", "10-x", "", "1"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        assert_eq!(
+            get_ranges_from_lines(lines).unwrap_err().to_string(),
+            "line 2, col 4: expected a number, found 'x'"
+        );
+
+        let lines = ["5-3"].iter().map(|s| s.to_string()).collect();
+        assert_eq!(
+            get_ranges_from_lines(lines).unwrap_err().to_string(),
+            "line 1, col 1: range starts at 5 but ends at 3"
+        );
+
+        let mut lines = example_lines();
+        lines[7] = "8a".to_string();
+        assert_eq!(
+            Day5::parse(lines).unwrap_err().to_string(),
+            "line 8, col 1: expected a number, found '8a'"
+        );
+    }
+}
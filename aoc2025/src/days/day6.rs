@@ -0,0 +1,368 @@
+//! Day 6: the cephalopod maths worksheet, read across and then down in columns.
+
+use crate::error::AocError;
+use crate::helpers::{self, Arithmetic, BigUint, Grid, Number};
+use crate::registry::Day;
+use crate::solution::{Params, Solution};
+use std::fmt::Display;
+use std::ops::Range;
+
+/// The registry entry for day 6, with the input split into lines.
+pub const DAY: Day = Day::new::<Day6>(6, "\n");
+
+/// Parses and solves day 6.
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = (Vec<Problem>, Arithmetic);
+
+    const PARAMS: &'static [&'static str] = &["arithmetic"];
+
+    fn parse(lines: Vec<String>) -> Result<Self::Input, AocError> {
+        Ok((problems_from_lines(lines)?, Arithmetic::default()))
+    }
+
+    fn configure((_, arithmetic): &mut Self::Input, params: &Params) -> Result<(), AocError> {
+        *arithmetic = params.get_or("arithmetic", *arithmetic)?;
+        Ok(())
+    }
+
+    fn part1((problems, arithmetic): &Self::Input) -> Result<Option<impl Display>, AocError> {
+        total_in(problems, Reading::Horizontal, *arithmetic).map(Some)
+    }
+
+    fn part2((problems, arithmetic): &Self::Input) -> Result<Option<impl Display>, AocError> {
+        total_in(problems, Reading::Vertical, *arithmetic).map(Some)
+    }
+}
+
+/// The operator under a problem.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Operation {
+    /// `+`, the numbers are summed.
+    Add,
+    /// `*`, the numbers are multiplied.
+    Multiply,
+}
+
+/// How the digits of a problem make up its numbers.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Reading {
+    /// One number per row, read left to right.
+    Horizontal,
+    /// One number per column, read top to bottom, the columns taken right to left.
+    Vertical,
+}
+
+/// One problem on the worksheet: the columns it spans, the operator under it, and the
+/// characters above the operator row with short lines padded by spaces.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Problem {
+    /// The columns of the worksheet the problem spans.
+    pub columns: Range<usize>,
+    /// The operator under the problem.
+    pub operation: Operation,
+    /// The digits and spaces above the operator row.
+    pub cells: Grid<char>,
+}
+
+impl Problem {
+    /// The numbers in the problem, skipping rows or columns with no digits, or `None` if one
+    /// does not fit in `N`.
+    pub fn numbers<N: Number>(&self, reading: Reading) -> Option<Vec<N>> {
+        let digits: Vec<Vec<u32>> = match reading {
+            Reading::Horizontal => self.cells.rows().map(|row| digits(row.iter())).collect(),
+            Reading::Vertical => (0..self.cells.width())
+                .rev()
+                .map(|x| digits(self.cells.column(x)))
+                .collect(),
+        };
+        digits
+            .iter()
+            .filter(|digits| !digits.is_empty())
+            .map(|digits| number_from_digits(digits))
+            .collect()
+    }
+
+    /// The numbers added or multiplied together, or `None` if that does not fit in `N`.
+    pub fn evaluate<N: Number>(&self, reading: Reading) -> Option<N> {
+        let mut numbers = self.numbers::<N>(reading)?.into_iter();
+        match self.operation {
+            Operation::Multiply => numbers.try_fold(N::from(1), N::checked_mul),
+            Operation::Add => numbers.try_fold(N::from(0), N::checked_add),
+        }
+    }
+}
+
+/// The digits in order with spaces skipped.
+fn digits<'a>(cells: impl Iterator<Item = &'a char>) -> Vec<u32> {
+    cells.filter_map(|c| c.to_digit(10)).collect()
+}
+
+fn number_from_digits<N: Number>(digits: &[u32]) -> Option<N> {
+    digits.iter().try_fold(N::from(0), |acc, &digit| {
+        acc.checked_mul(N::from(10))?
+            .checked_add(N::from(u128::from(digit)))
+    })
+}
+
+/// Splits the worksheet into problems at the columns that are blank on every line, the
+/// operator row included. Lines shorter than the widest are treated as ending in spaces.
+pub fn problems_from_lines(lines: Vec<String>) -> Result<Vec<Problem>, AocError> {
+    if lines.len() < 2 {
+        return Err(AocError::malformed(
+            0,
+            0,
+            "expected rows of numbers followed by a row of operators",
+        ));
+    }
+    let operation_line = lines.len() - 1;
+    for (line, row) in lines[..operation_line].iter().enumerate() {
+        if let Some((column, c)) = row
+            .chars()
+            .enumerate()
+            .find(|(_, c)| !c.is_ascii_digit() && *c != ' ')
+        {
+            return Err(AocError::malformed(
+                line,
+                column,
+                format!("expected a digit or space, found '{c}'"),
+            ));
+        }
+    }
+    if let Some((column, c)) = lines[operation_line]
+        .chars()
+        .enumerate()
+        .find(|(_, c)| !matches!(c, '+' | '*' | ' '))
+    {
+        return Err(AocError::unsupported_operation(operation_line, column, c));
+    }
+    let rows: Vec<Vec<char>> = lines.iter().map(|line| line.chars().collect()).collect();
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+    let cell = |x: usize, y: usize| rows[y].get(x).copied().unwrap_or(' ');
+
+    let mut problems = Vec::new();
+    let mut start = None;
+    for x in 0..=width {
+        let blank = x == width || (0..rows.len()).all(|y| cell(x, y) == ' ');
+        match (blank, start) {
+            (false, None) => start = Some(x),
+            (true, Some(first)) => {
+                let columns = first..x;
+                let operation = operation_under(&rows[operation_line], operation_line, &columns)?;
+                let cells = (0..operation_line)
+                    .flat_map(|y| columns.clone().map(move |x| (x, y)))
+                    .map(|(x, y)| cell(x, y))
+                    .collect();
+                problems.push(Problem {
+                    cells: Grid::new(columns.len(), operation_line, cells),
+                    columns,
+                    operation,
+                });
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    Ok(problems)
+}
+
+fn operation_under(
+    row: &[char],
+    line: usize,
+    columns: &Range<usize>,
+) -> Result<Operation, AocError> {
+    let mut found = columns
+        .clone()
+        .filter_map(|column| row.get(column).map(|&c| (column, c)))
+        .filter(|(_, c)| *c != ' ');
+    let operation = match found.next() {
+        Some((_, '+')) => Operation::Add,
+        Some((_, '*')) => Operation::Multiply,
+        Some((column, c)) => return Err(AocError::unsupported_operation(line, column, c)),
+        None => {
+            return Err(AocError::malformed(
+                line,
+                columns.start,
+                format!(
+                    "expected an operator under columns {}-{}",
+                    columns.start + 1,
+                    columns.end
+                ),
+            ));
+        }
+    };
+    match found.next() {
+        Some((column, c)) => Err(AocError::malformed(
+            line,
+            column,
+            format!("expected one operator per problem, found another '{c}'"),
+        )),
+        None => Ok(operation),
+    }
+}
+
+/// The sum of every problem's answer, read as `reading` and totalled in `arithmetic`, or an
+/// error if it does not fit in `u128` under [`Arithmetic::Native`].
+pub fn total_in(
+    problems: &[Problem],
+    reading: Reading,
+    arithmetic: Arithmetic,
+) -> Result<String, AocError> {
+    let total = match arithmetic {
+        Arithmetic::Native => grand_total::<u128>(problems, reading).map(|total| total.to_string()),
+        Arithmetic::Big => grand_total::<BigUint>(problems, reading).map(|total| total.to_string()),
+    };
+    total.ok_or_else(|| {
+        let name = match reading {
+            Reading::Horizontal => "across",
+            Reading::Vertical => "down",
+        };
+        helpers::too_large(&format!("the grand total with numbers read {name}"))
+    })
+}
+
+/// [`total_in`] as a number of type `N`, or `None` if it does not fit.
+pub fn grand_total<N: Number>(problems: &[Problem], reading: Reading) -> Option<N> {
+    problems.iter().try_fold(N::from(0), |total, problem| {
+        total.checked_add(problem.evaluate::<N>(reading)?)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+
+    fn test_lines() -> Vec<String> {
+        fixtures::example_lines(6, "example")
+    }
+
+    #[test]
+    fn confirm_split_on_whitespace_is_a_thing() {
+        assert_eq!(
+            "*   +   *   +".split_whitespace().collect::<Vec<&str>>(),
+            vec!["*", "+", "*", "+"]
+        )
+    }
+
+    #[test]
+    fn problems_are_split_into_blocks() {
+        let problems = problems_from_lines(test_lines()).unwrap();
+        let spans: Vec<(Range<usize>, Operation)> = problems
+            .iter()
+            .map(|problem| (problem.columns.clone(), problem.operation))
+            .collect();
+        assert_eq!(
+            spans,
+            vec![
+                (0..3, Operation::Multiply),
+                (4..7, Operation::Add),
+                (8..11, Operation::Multiply),
+                (12..15, Operation::Add),
+            ]
+        );
+        assert_eq!(problems[3].cells.to_string(), "64 \n23 \n314");
+    }
+
+    #[test]
+    fn ragged_lines_are_padded_with_spaces() {
+        let lines: Vec<String> = [
+            "123 328  51 64",
+            " 45 64  387 23",
+            "  6 98  215 314",
+            "*   +   *   +",
+        ]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+        assert_eq!(
+            problems_from_lines(lines).unwrap(),
+            problems_from_lines(test_lines()).unwrap()
+        );
+    }
+
+    #[test]
+    fn day1_example_test() {
+        let problems = problems_from_lines(test_lines()).unwrap();
+        let numbers: Option<Vec<u128>> = problems[0].numbers(Reading::Horizontal);
+        assert_eq!(numbers, Some(vec![123, 45, 6]));
+        assert_eq!(
+            grand_total::<u128>(&problems, Reading::Horizontal),
+            Some(4277556)
+        );
+    }
+
+    #[test]
+    fn day2_example_test() {
+        let problems = problems_from_lines(test_lines()).unwrap();
+        let numbers: Option<Vec<u128>> = problems[3].numbers(Reading::Vertical);
+        assert_eq!(numbers, Some(vec![4, 431, 623]));
+        assert_eq!(
+            grand_total::<u128>(&problems, Reading::Vertical),
+            Some(3263827)
+        );
+    }
+
+    #[test]
+    fn big_arithmetic_keeps_products_past_u128() {
+        let mut lines = vec!["9999999999".to_string(); 4];
+        lines.push("*".to_string());
+        let mut input = Day6::parse(lines).unwrap();
+        let mut params = Params::new();
+        params.set("arithmetic", "big");
+        Day6::configure(&mut input, &params).unwrap();
+        assert_eq!(
+            Day6::part2(&input).unwrap().unwrap().to_string(),
+            "9990004498800209974802099880004499900001"
+        );
+        assert_eq!(
+            Day6::part1(&input).unwrap().unwrap().to_string(),
+            "9999999996000000000599999999960000000001"
+        );
+    }
+
+    #[test]
+    fn native_overflow_suggests_big_arithmetic() {
+        let mut lines = vec!["9999999999".to_string(); 4];
+        lines.push("*".to_string());
+        let mut input = Day6::parse(lines).unwrap();
+        Day6::configure(&mut input, &Params::new()).unwrap();
+        assert_eq!(
+            Day6::part1(&input).err().unwrap().to_string(),
+            "the grand total with numbers read across does not fit in 128 bits, try arithmetic=big"
+        );
+        assert_eq!(
+            Day6::part2(&input).err().unwrap().to_string(),
+            "the grand total with numbers read down does not fit in 128 bits, try arithmetic=big"
+        );
+
+        let problems = problems_from_lines(vec!["9".repeat(40), "+".to_string()]).unwrap();
+        assert_eq!(problems[0].numbers::<u128>(Reading::Horizontal), None);
+        assert_eq!(grand_total::<u128>(&problems, Reading::Horizontal), None);
+    }
+
+    #[test]
+    fn unknown_operator_is_reported_with_position() {
+        let mut lines = test_lines();
+        lines[3] = "*   +   *  -   ".to_string();
+        assert_eq!(
+            problems_from_lines(lines).unwrap_err().to_string(),
+            "line 4, col 12: unknown operator '-'"
+        );
+
+        let mut lines = test_lines();
+        lines[3] = "*   +   *      ".to_string();
+        assert_eq!(
+            problems_from_lines(lines).unwrap_err().to_string(),
+            "line 4, col 13: expected an operator under columns 13-15"
+        );
+
+        let mut lines = test_lines();
+        lines[3] = "*   ++  *   +  ".to_string();
+        assert_eq!(
+            problems_from_lines(lines).unwrap_err().to_string(),
+            "line 4, col 6: expected one operator per problem, found another '+'"
+        );
+    }
+}
@@ -0,0 +1,198 @@
+//! Day 7: beams falling through a manifold of splitters, and the timelines they make.
+
+use crate::error::AocError;
+use crate::helpers::Grid;
+use crate::registry::Day;
+use crate::solution::Solution;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+
+/// The registry entry for day 7, with the input split into lines.
+pub const DAY: Day = Day::new::<Day7>(7, "\n");
+
+/// Parses and solves day 7.
+pub struct Day7;
+
+/// The number of beams at each column, starting with the one at `S`, the columns of the
+/// splitters on each line below it, and the width of the manifold.
+pub type BeamsAndSplitters = (HashMap<usize, usize>, Vec<HashSet<usize>>, usize);
+
+impl Solution for Day7 {
+    type Input = BeamsAndSplitters;
+
+    fn parse(lines: Vec<String>) -> Result<Self::Input, AocError> {
+        get_start_and_splitter_locations(lines)
+    }
+
+    fn part1(
+        (start_location, splitter_locations, width): &Self::Input,
+    ) -> Result<Option<impl Display>, AocError> {
+        split_and_timeline_count(start_location, splitter_locations, *width)
+            .map(|(splits, _)| Some(splits))
+    }
+
+    fn part2(
+        (start_location, splitter_locations, width): &Self::Input,
+    ) -> Result<Option<impl Display>, AocError> {
+        split_and_timeline_count(start_location, splitter_locations, *width)
+            .map(|(_, timelines)| Some(timelines))
+    }
+}
+
+/// The beam entering at `S` on the first line, and the splitters below it.
+pub fn get_start_and_splitter_locations(lines: Vec<String>) -> Result<BeamsAndSplitters, AocError> {
+    let manifold = Grid::parse(&lines, |c| matches!(c, '.' | '^' | 'S').then_some(c))?;
+    let (start_location, _) =
+        manifold
+            .find(&'S')
+            .filter(|(_, y)| *y == 0)
+            .ok_or(AocError::malformed(
+                0,
+                0,
+                "expected a start 'S' on the first line",
+            ))?;
+    let splitter_locations = manifold
+        .rows()
+        .skip(1)
+        .map(|row| {
+            row.iter()
+                .enumerate()
+                .filter(|(_, c)| **c == '^')
+                .map(|(i, _)| i)
+                .collect()
+        })
+        .collect();
+    Ok((
+        HashMap::from([(start_location, 1)]),
+        splitter_locations,
+        manifold.width(),
+    ))
+}
+
+/// Moves the beams down past one row of splitters in a manifold `width` columns wide, returning
+/// where they end up and how many splitters were hit, or an error if a beam hits a splitter on
+/// the edge. `line` is only used to report errors.
+pub fn split_beams_on_splitters(
+    beam_locations: &HashMap<usize, usize>,
+    splitter_locations: &HashSet<usize>,
+    width: usize,
+    line: usize,
+) -> Result<(HashMap<usize, usize>, usize), AocError> {
+    let mut new_locations = HashMap::new();
+    let mut split_count = 0;
+    for (&location, count) in beam_locations {
+        if splitter_locations.contains(&location) {
+            let left = location.checked_sub(1);
+            let right = Some(location + 1).filter(|&right| right < width);
+            let (Some(left), Some(right)) = (left, right) else {
+                return Err(AocError::malformed(
+                    line,
+                    location,
+                    "a beam hits a splitter on the edge, which would split it out of the manifold",
+                ));
+            };
+            *new_locations.entry(left).or_insert(0) += count;
+            *new_locations.entry(right).or_insert(0) += count;
+            split_count += 1;
+        } else {
+            let un_touched = new_locations.entry(location).or_insert(0);
+            *un_touched += count;
+        }
+    }
+    Ok((new_locations, split_count))
+}
+
+/// How many times the beams split on the way down, and how many timelines come out the bottom.
+pub fn split_and_timeline_count(
+    start_location: &HashMap<usize, usize>,
+    splitter_locations: &[HashSet<usize>],
+    width: usize,
+) -> Result<(usize, usize), AocError> {
+    let mut current_beams = start_location.clone();
+    let mut split_count = 0;
+    for (row, locations) in splitter_locations.iter().enumerate() {
+        // The splitters start on the line below `S`.
+        let (new_locations, splits) =
+            split_beams_on_splitters(&current_beams, locations, width, row + 1)?;
+        split_count += splits;
+        current_beams = new_locations;
+    }
+    let timelines = current_beams.values().sum();
+    Ok((split_count, timelines))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+
+    fn test_input() -> Vec<String> {
+        fixtures::example_lines(7, "example")
+    }
+
+    #[test]
+    fn get_start_and_splitter_locations_test() {
+        let first_5_lines = test_input().iter().take(5).map(|s| s.to_string()).collect();
+        let (beam_locations, splitter_locations, width) =
+            get_start_and_splitter_locations(first_5_lines).unwrap();
+        assert_eq!(beam_locations, HashMap::from([(7, 1)]));
+        assert_eq!(
+            splitter_locations,
+            vec![
+                HashSet::new(),
+                HashSet::from([7]),
+                HashSet::new(),
+                HashSet::from([6, 8]),
+            ]
+        );
+        assert_eq!(width, 15);
+    }
+
+    #[test]
+    fn split_beams_on_splitters_test() {
+        let beam_locations = HashMap::from([(4, 1), (3, 1), (6, 1)]);
+        let splitter_locations = HashSet::from([4, 6, 10]);
+
+        assert_eq!(
+            split_beams_on_splitters(&beam_locations, &splitter_locations, 11, 0).unwrap(),
+            (HashMap::from([(3, 2), (5, 2), (7, 1)]), 2)
+        )
+    }
+
+    #[test]
+    fn missing_start_is_an_error() {
+        let lines = test_input().into_iter().skip(1).collect();
+        assert_eq!(
+            get_start_and_splitter_locations(lines)
+                .unwrap_err()
+                .to_string(),
+            "line 1, col 1: expected a start 'S' on the first line"
+        );
+    }
+
+    fn lines(rows: &[&str]) -> Vec<String> {
+        rows.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn splitter_on_the_edge_is_an_error_when_a_beam_hits_it() {
+        for rows in [["S..", "^..", "..."], ["..S", "..^", "..."]] {
+            let input = Day7::parse(lines(&rows)).unwrap();
+            let column = rows[0].find('S').unwrap() + 1;
+            assert_eq!(
+                Day7::part1(&input).err().unwrap().to_string(),
+                format!(
+                    "line 2, col {column}: a beam hits a splitter on the edge, which would \
+                     split it out of the manifold"
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn splitter_on_the_edge_no_beam_reaches_is_fine() {
+        let input = Day7::parse(lines(&[".S..", "^^..", "...^"])).unwrap();
+        assert_eq!(Day7::part1(&input).unwrap().unwrap().to_string(), "1");
+        assert_eq!(Day7::part2(&input).unwrap().unwrap().to_string(), "2");
+    }
+}
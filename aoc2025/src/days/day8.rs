@@ -0,0 +1,361 @@
+//! Day 8: connecting junction boxes closest first into circuits.
+
+use crate::error::AocError;
+use crate::helpers::{self, DisjointSet};
+use crate::kd_tree::KdTree;
+use crate::registry::Day;
+use crate::solution::{Params, Solution};
+use std::cmp::Ordering;
+use std::fmt::Display;
+
+/// The registry entry for day 8, with the input split into lines.
+pub const DAY: Day = Day::new::<Day8>(8, "\n");
+
+/// Parses and solves day 8.
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = (Vec<Point>, Circuits);
+
+    const PARAMS: &'static [&'static str] = &["pairs", "circuits"];
+
+    fn parse(lines: Vec<String>) -> Result<Self::Input, AocError> {
+        Ok((points_from_lines(lines)?, Circuits::default()))
+    }
+
+    fn configure((_, circuits): &mut Self::Input, params: &Params) -> Result<(), AocError> {
+        circuits.pairs = params.get_or("pairs", circuits.pairs)?;
+        circuits.largest = params.get_or("circuits", circuits.largest)?;
+        Ok(())
+    }
+
+    fn part1((points, circuits): &Self::Input) -> Result<Option<impl Display>, AocError> {
+        Ok(Some(size_of_n_biggest_circuits(
+            points,
+            circuits.pairs,
+            circuits.largest,
+        )))
+    }
+
+    fn part2((points, _): &Self::Input) -> Result<Option<impl Display>, AocError> {
+        product_of_last_2(points).map(Some)
+    }
+}
+
+/// How many of the closest pairs to connect, and how many of the largest circuits to
+/// multiply together.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Circuits {
+    /// How many of the closest pairs to connect for part 1.
+    pub pairs: usize,
+    /// How many of the largest circuits to multiply together.
+    pub largest: usize,
+}
+
+impl Default for Circuits {
+    fn default() -> Circuits {
+        Circuits {
+            pairs: 1000,
+            largest: 3,
+        }
+    }
+}
+
+/// A junction box, with `id` its line in the input.
+#[derive(Debug, PartialEq, PartialOrd, Eq, Hash)]
+pub struct Point {
+    /// The first coordinate.
+    pub x: i64,
+    /// The second coordinate.
+    pub y: i64,
+    /// The third coordinate.
+    pub z: i64,
+    /// The line of the input the point came from, counting from 0.
+    pub id: usize,
+}
+
+impl Point {
+    /// The point at `(x, y, z)` read from line `id`.
+    pub fn new(x: i64, y: i64, z: i64, id: usize) -> Point {
+        Point { x, y, z, id }
+    }
+
+    /// The coordinates as an array, the form [`KdTree`] takes.
+    pub fn coordinates(&self) -> [i64; 3] {
+        [self.x, self.y, self.z]
+    }
+}
+
+/// Two points, the one with the lower id first, and the square of the distance between them.
+/// Pairs are ordered by distance and then by the ids of their points, so pairs at the same
+/// distance come out in the same order as [`closest_pairs`] streams them.
+#[derive(Debug, Clone)]
+pub struct PointPair<'a> {
+    /// The point with the lower id.
+    pub first: &'a Point,
+    /// The point with the higher id.
+    pub second: &'a Point,
+    /// The square of the distance between the two points.
+    pub square_dist: i64,
+}
+
+impl<'a> PointPair<'a> {
+    /// The pair joining `p1` and `p2`, in either order.
+    pub fn new(p1: &'a Point, p2: &'a Point) -> PointPair<'a> {
+        let dist = (p2.x - p1.x).pow(2) + (p2.y - p1.y).pow(2) + (p2.z - p1.z).pow(2);
+        if p1.id < p2.id {
+            PointPair {
+                first: p1,
+                second: p2,
+                square_dist: dist,
+            }
+        } else {
+            PointPair {
+                first: p2,
+                second: p1,
+                square_dist: dist,
+            }
+        }
+    }
+}
+
+impl Eq for PointPair<'_> {}
+
+impl PartialOrd<Self> for PointPair<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PointPair<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.square_dist, self.first.id, self.second.id).cmp(&(
+            other.square_dist,
+            other.first.id,
+            other.second.id,
+        ))
+    }
+}
+
+impl PartialEq for PointPair<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+/// One point per line, like `162,817,812`.
+pub fn points_from_lines(lines: Vec<String>) -> Result<Vec<Point>, AocError> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(index, line)| {
+            let elements: Vec<i64> = helpers::parse_separated_numbers(line, ',', index)?;
+            if elements.len() != 3 {
+                return Err(AocError::malformed(
+                    index,
+                    0,
+                    format!("expected 3 coordinates, found {}", elements.len()),
+                ));
+            }
+            Ok(Point::new(elements[0], elements[1], elements[2], index))
+        })
+        .collect()
+}
+
+/// Every pair of `points`, closest first, streamed from `tree` built by [`kd_tree`].
+pub fn closest_pairs<'a>(
+    points: &'a [Point],
+    tree: &'a KdTree,
+) -> impl Iterator<Item = PointPair<'a>> {
+    tree.pairs_by_distance()
+        .map(|(_, first, second)| PointPair::new(&points[first], &points[second]))
+}
+
+/// A k-d tree over `points`, for [`closest_pairs`] and the minimum spanning tree.
+pub fn kd_tree(points: &[Point]) -> KdTree {
+    let coordinates: Vec<[i64; 3]> = points.iter().map(Point::coordinates).collect();
+    KdTree::new(&coordinates)
+}
+
+fn combine_circuits<'a>(
+    points: &[Point],
+    closest_pairs: impl Iterator<Item = PointPair<'a>>,
+    largest: usize,
+) -> u64 {
+    let mut circuits = DisjointSet::new(points.len());
+    for pair in closest_pairs {
+        circuits.union(pair.first.id, pair.second.id);
+    }
+    circuits.largest(largest).iter().product::<usize>() as u64
+}
+
+/// The product of the sizes of the `largest` circuits after connecting the `n` closest pairs.
+pub fn size_of_n_biggest_circuits(points: &[Point], n: usize, largest: usize) -> u64 {
+    let tree = kd_tree(points);
+    combine_circuits(points, closest_pairs(points, &tree).take(n), largest)
+}
+
+/// Joining pairs closest first, the pair that makes a single circuit is the longest edge of the
+/// minimum spanning tree. The answer is the product of its points' x coordinates, or an error
+/// with fewer than two points, which are a single circuit before any pair is joined, or when
+/// the product is negative or does not fit in a `u64`.
+pub fn product_of_last_2(points: &[Point]) -> Result<u64, AocError> {
+    let (_, first, second) = kd_tree(points)
+        .minimum_spanning_tree()
+        .into_iter()
+        .max()
+        .ok_or(AocError::Invalid(format!(
+            "expected at least 2 points to join, found {}",
+            points.len()
+        )))?;
+    let (x1, x2) = (points[first].x, points[second].x);
+    let product = x1.checked_mul(x2).ok_or(AocError::Invalid(format!(
+        "the product of x coordinates {x1} and {x2} does not fit in 64 bits"
+    )))?;
+    u64::try_from(product).map_err(|_| {
+        AocError::Invalid(format!(
+            "the product of x coordinates {x1} and {x2} is negative"
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+
+    fn example_data() -> Vec<String> {
+        fixtures::example_lines(8, "example")
+    }
+
+    fn short_example_data() -> Vec<String> {
+        "862,61,35
+984,92,344
+425,690,689"
+            .split_whitespace()
+            .map(|e| e.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn points_from_lines_test() {
+        let points = points_from_lines(short_example_data()).unwrap();
+        assert_eq!(
+            points,
+            vec![
+                Point::new(862, 61, 35, 0),
+                Point::new(984, 92, 344, 1),
+                Point::new(425, 690, 689, 2)
+            ]
+        )
+    }
+
+    #[test]
+    fn square_dist_on_points_is_correct() {
+        let p1 = Point::new(1, 2, 3, 0);
+        let p2 = Point::new(3, 3, 4, 1);
+        let pair = PointPair::new(&p1, &p2);
+        assert_eq!(pair.square_dist, 6);
+    }
+
+    #[test]
+    fn pairs_at_the_same_distance_are_ordered_by_id() {
+        let points = [
+            Point::new(0, 0, 0, 0),
+            Point::new(1, 0, 0, 1),
+            Point::new(2, 0, 0, 2),
+        ];
+        let near = PointPair::new(&points[1], &points[0]);
+        let far = PointPair::new(&points[2], &points[1]);
+        assert_eq!(near.first.id, 0);
+        assert_eq!(near.square_dist, far.square_dist);
+        assert_ne!(near, far);
+        assert_eq!(near.cmp(&far), Ordering::Less);
+        assert_eq!(near, PointPair::new(&points[0], &points[1]));
+    }
+
+    fn all_point_pairs(points: &[Point]) -> Vec<PointPair<'_>> {
+        let mut pairs = Vec::new();
+        for i in 0..points.len() {
+            for j in (i + 1)..points.len() {
+                pairs.push(PointPair::new(&points[i], &points[j]))
+            }
+        }
+        pairs
+    }
+
+    #[test]
+    fn get_all_pairs_test() {
+        let points = points_from_lines(example_data()).unwrap();
+        let tree = kd_tree(&points);
+        assert_eq!(closest_pairs(&points, &tree).count(), 20 * (20 - 1) / 2)
+    }
+
+    #[test]
+    fn closest_pairs_are_in_distance_order() {
+        let points = points_from_lines(example_data()).unwrap();
+        let mut all_pairs = all_point_pairs(&points);
+        all_pairs.sort();
+        let tree = kd_tree(&points);
+        let streamed: Vec<PointPair> = closest_pairs(&points, &tree).collect();
+        assert_eq!(streamed, all_pairs);
+    }
+
+    #[test]
+    fn test_closest_points() {
+        let points = points_from_lines(example_data()).unwrap();
+        let tree = kd_tree(&points);
+        let close_pairs: Vec<PointPair> = closest_pairs(&points, &tree).take(2).collect();
+
+        assert_eq!(
+            close_pairs[0],
+            PointPair::new(
+                &Point::new(162, 817, 812, 0),
+                &Point::new(425, 690, 689, 19)
+            )
+        );
+
+        assert_eq!(
+            close_pairs[1],
+            PointPair::new(&Point::new(162, 817, 812, 0), &Point::new(431, 825, 988, 7))
+        );
+    }
+
+    #[test]
+    fn circuits_from_params() {
+        let mut input = Day8::parse(example_data()).unwrap();
+        let mut params = Params::new();
+        params.set("pairs", "10");
+        Day8::configure(&mut input, &params).unwrap();
+        assert_eq!(Day8::part1(&input).unwrap().unwrap().to_string(), "40");
+
+        params.set("circuits", "1");
+        Day8::configure(&mut input, &params).unwrap();
+        assert_eq!(Day8::part1(&input).unwrap().unwrap().to_string(), "5");
+    }
+
+    #[test]
+    fn part2_with_one_point_is_an_error() {
+        let input = Day8::parse(vec!["1,2,3".to_string()]).unwrap();
+        assert_eq!(
+            Day8::part2(&input).err().unwrap().to_string(),
+            "expected at least 2 points to join, found 1"
+        );
+        assert!(product_of_last_2(&[]).is_err());
+    }
+
+    #[test]
+    fn part2_product_must_be_a_u64() {
+        let points = [Point::new(-3, 0, 0, 0), Point::new(5, 0, 0, 1)];
+        assert_eq!(
+            product_of_last_2(&points).unwrap_err().to_string(),
+            "the product of x coordinates -3 and 5 is negative"
+        );
+        let big = i64::MAX / 2;
+        let points = [Point::new(big, 0, 0, 0), Point::new(big, 1, 0, 1)];
+        assert_eq!(
+            product_of_last_2(&points).unwrap_err().to_string(),
+            format!("the product of x coordinates {big} and {big} does not fit in 64 bits")
+        );
+    }
+}
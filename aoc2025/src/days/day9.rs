@@ -0,0 +1,406 @@
+//! Day 9: the largest rectangle with red tiles at two opposite corners.
+
+use crate::error::AocError;
+use crate::helpers::{self, Grid, Position};
+use crate::registry::Day;
+use crate::solution::Solution;
+use std::fmt::Display;
+
+/// The registry entry for day 9, with the input split into lines.
+pub const DAY: Day = Day::new::<Day9>(9, "\n");
+
+/// Parses and solves day 9.
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<(u64, u64)>;
+
+    fn parse(lines: Vec<String>) -> Result<Vec<(u64, u64)>, AocError> {
+        lines_to_points(lines)
+    }
+
+    fn part1(points: &Vec<(u64, u64)>) -> Result<Option<impl Display>, AocError> {
+        Ok(Some(find_max_area_fast(points)))
+    }
+
+    fn part2(points: &Vec<(u64, u64)>) -> Result<Option<impl Display>, AocError> {
+        find_max_area_inside(points).map(Some)
+    }
+}
+
+/// The red tiles, one `x,y` per line.
+pub fn lines_to_points(lines: Vec<String>) -> Result<Vec<(u64, u64)>, AocError> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(line, s)| {
+            let elems: Vec<u64> = helpers::parse_separated_numbers(s, ',', line)?;
+            if elems.len() != 2 {
+                return Err(AocError::malformed(
+                    line,
+                    0,
+                    format!("expected 2 coordinates, found {}", elems.len()),
+                ));
+            }
+            Ok((elems[0], elems[1]))
+        })
+        .collect()
+}
+
+/// Each point must share a row or column with the one before it, wrapping around at the end,
+/// so that the points trace a rectilinear polygon. Only part 2 needs this.
+fn check_rectilinear(points: &[(u64, u64)]) -> Result<(), AocError> {
+    for (line, point) in points.iter().enumerate() {
+        let previous = points[(line + points.len() - 1) % points.len()];
+        if point.0 != previous.0 && point.1 != previous.1 {
+            return Err(AocError::malformed(
+                line,
+                0,
+                format!(
+                    "{},{} is not in a row or column with {},{}",
+                    point.0, point.1, previous.0, previous.1
+                ),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// The number of tiles in the rectangle with opposite corners `first` and `second`.
+pub fn area_with_corners(first: (u64, u64), second: (u64, u64)) -> u64 {
+    (first.0.abs_diff(second.0) + 1) * (first.1.abs_diff(second.1) + 1)
+}
+
+/// Reference implementation for the differential tests against [`find_max_area_fast`].
+#[cfg(test)]
+fn find_max_area(points: &[(u64, u64)]) -> u64 {
+    let mut max_area = 0;
+    for i in 0..points.len() {
+        for j in (i + 1)..points.len() {
+            let area = area_with_corners(points[i], points[j]);
+            if area > max_area {
+                max_area = area;
+            }
+        }
+    }
+
+    max_area
+}
+
+/// The largest rectangle with red tiles at two opposite corners.
+///
+/// The same answer as checking every pair, but only pairs a corner from the lower-left staircase
+/// with one from the upper-right staircase, then repeats with the y axis flipped for the other
+/// diagonal. A point can only be a best lower-left corner if no other point is both left of and
+/// below it.
+pub fn find_max_area_fast(points: &[(u64, u64)]) -> u64 {
+    if points.len() < 2 {
+        return 0;
+    }
+    let top = points.iter().map(|p| p.1).max().unwrap_or(0);
+    let flipped: Vec<(u64, u64)> = points.iter().map(|&(x, y)| (x, top - y)).collect();
+    max_area_lower_left_to_upper_right(points).max(max_area_lower_left_to_upper_right(&flipped))
+}
+
+fn max_area_lower_left_to_upper_right(points: &[(u64, u64)]) -> u64 {
+    let mut sorted = points.to_vec();
+    sorted.sort();
+    let mut lower_left: Vec<(u64, u64)> = Vec::new();
+    for &point in &sorted {
+        if lower_left.last().is_none_or(|last| point.1 < last.1) {
+            lower_left.push(point);
+        }
+    }
+    let mut upper_right: Vec<(u64, u64)> = Vec::new();
+    for &point in sorted.iter().rev() {
+        if upper_right.last().is_none_or(|last| point.1 > last.1) {
+            upper_right.push(point);
+        }
+    }
+    upper_right.reverse();
+
+    // Both staircases run left to right and downwards, so the upper-right corners that fit each
+    // lower-left corner form a window that only ever moves right.
+    let mut windows = Vec::with_capacity(lower_left.len());
+    let (mut first, mut end) = (0, 0);
+    for corner in &lower_left {
+        while first < upper_right.len() && upper_right[first].0 < corner.0 {
+            first += 1;
+        }
+        while end < upper_right.len() && upper_right[end].1 >= corner.1 {
+            end += 1;
+        }
+        windows.push(first..end.max(first));
+    }
+    best_in_windows(
+        &lower_left,
+        &upper_right,
+        &windows,
+        0..lower_left.len(),
+        0..upper_right.len(),
+    )
+}
+
+/// Divide and conquer over the lower-left corners. The area is a Monge array over the two
+/// staircases, so the best partner never moves left as the lower-left corner moves right.
+fn best_in_windows(
+    lower_left: &[(u64, u64)],
+    upper_right: &[(u64, u64)],
+    windows: &[std::ops::Range<usize>],
+    rows: std::ops::Range<usize>,
+    columns: std::ops::Range<usize>,
+) -> u64 {
+    if rows.is_empty() {
+        return 0;
+    }
+    let mid = rows.start + rows.len() / 2;
+    let window = &windows[mid];
+    let candidates = window.start.max(columns.start)..window.end.min(columns.end);
+    let (split_left, split_right, best) = match candidates
+        .clone()
+        .map(|j| (area_with_corners(lower_left[mid], upper_right[j]), j))
+        .max_by(|a, b| a.0.cmp(&b.0).then(b.1.cmp(&a.1)))
+    {
+        Some((area, j)) => (j + 1, j, area),
+        None => (
+            window.end.min(columns.end),
+            window.start.max(columns.start),
+            0,
+        ),
+    };
+    let left = best_in_windows(
+        lower_left,
+        upper_right,
+        windows,
+        rows.start..mid,
+        columns.start..split_left.max(columns.start),
+    );
+    let right = best_in_windows(
+        lower_left,
+        upper_right,
+        windows,
+        mid + 1..rows.end,
+        split_right.min(columns.end)..columns.end,
+    );
+    best.max(left).max(right)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Tile {
+    Unknown,
+    Boundary,
+    Outside,
+}
+
+/// The polygon traced by the points, on a grid compressed so that each used coordinate and each
+/// gap between neighbouring used coordinates is a single cell. A border of gap cells surrounds
+/// it so the outside is connected.
+struct CompressedPolygon {
+    xs: Vec<u64>,
+    ys: Vec<u64>,
+    // outside_before[y][x] counts the outside cells above and to the left of (x, y).
+    outside_before: Vec<Vec<u32>>,
+}
+
+impl CompressedPolygon {
+    fn new(points: &[(u64, u64)]) -> Result<CompressedPolygon, AocError> {
+        check_rectilinear(points)?;
+        let mut xs: Vec<u64> = points.iter().map(|p| p.0).collect();
+        let mut ys: Vec<u64> = points.iter().map(|p| p.1).collect();
+        xs.sort();
+        xs.dedup();
+        ys.sort();
+        ys.dedup();
+
+        let mut tiles = Grid::new(
+            2 * xs.len() + 1,
+            2 * ys.len() + 1,
+            vec![Tile::Unknown; (2 * xs.len() + 1) * (2 * ys.len() + 1)],
+        );
+        for (i, &start) in points.iter().enumerate() {
+            let end = points[(i + 1) % points.len()];
+            let (x1, x2) = min_max(compress(&xs, start.0), compress(&xs, end.0));
+            let (y1, y2) = min_max(compress(&ys, start.1), compress(&ys, end.1));
+            for y in y1..=y2 {
+                for x in x1..=x2 {
+                    tiles[(x, y)] = Tile::Boundary;
+                }
+            }
+        }
+
+        let mut to_visit = vec![(0, 0)];
+        tiles[(0, 0)] = Tile::Outside;
+        while let Some(position) = to_visit.pop() {
+            let unknown: Vec<Position> = tiles
+                .neighbors4(position)
+                .filter(|p| tiles[*p] == Tile::Unknown)
+                .collect();
+            for neighbor in unknown {
+                tiles[neighbor] = Tile::Outside;
+                to_visit.push(neighbor);
+            }
+        }
+
+        let mut outside_before = vec![vec![0; tiles.width() + 1]; tiles.height() + 1];
+        for (y, row) in tiles.rows().enumerate() {
+            for (x, tile) in row.iter().enumerate() {
+                outside_before[y + 1][x + 1] = outside_before[y][x + 1] + outside_before[y + 1][x]
+                    - outside_before[y][x]
+                    + u32::from(*tile == Tile::Outside);
+            }
+        }
+        Ok(CompressedPolygon {
+            xs,
+            ys,
+            outside_before,
+        })
+    }
+
+    fn contains_rectangle(&self, first: (u64, u64), second: (u64, u64)) -> bool {
+        let (x1, x2) = min_max(compress(&self.xs, first.0), compress(&self.xs, second.0));
+        let (y1, y2) = min_max(compress(&self.ys, first.1), compress(&self.ys, second.1));
+        let outside = &self.outside_before;
+        outside[y2 + 1][x2 + 1] + outside[y1][x1] - outside[y1][x2 + 1] - outside[y2 + 1][x1] == 0
+    }
+}
+
+fn compress(coordinates: &[u64], value: u64) -> usize {
+    let index = coordinates
+        .binary_search(&value)
+        .expect("only compresses coordinates of the polygon's own points");
+    2 * index + 1
+}
+
+fn min_max(a: usize, b: usize) -> (usize, usize) {
+    (a.min(b), a.max(b))
+}
+
+/// The largest rectangle with red tiles at two opposite corners that lies entirely inside the
+/// polygon they trace.
+pub fn find_max_area_inside(points: &[(u64, u64)]) -> Result<u64, AocError> {
+    let polygon = CompressedPolygon::new(points)?;
+    let mut max_area = 0;
+    for i in 0..points.len() {
+        for j in (i + 1)..points.len() {
+            let area = area_with_corners(points[i], points[j]);
+            if area > max_area && polygon.contains_rectangle(points[i], points[j]) {
+                max_area = area;
+            }
+        }
+    }
+    Ok(max_area)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use crate::solution::Params;
+
+    fn example_lines() -> Vec<String> {
+        fixtures::example_lines(9, "example")
+    }
+
+    #[test]
+    fn lines_to_points_test() {
+        let points = lines_to_points(example_lines()).unwrap();
+        assert_eq!(
+            points,
+            vec![
+                (7, 1),
+                (11, 1),
+                (11, 7),
+                (9, 7),
+                (9, 5),
+                (2, 5),
+                (2, 3),
+                (7, 3),
+            ]
+        )
+    }
+
+    #[test]
+    fn area_with_corners_test() {
+        assert_eq!(area_with_corners((2, 5), (9, 7)), 24);
+        assert_eq!(area_with_corners((7, 1), (11, 7)), 35);
+        assert_eq!(area_with_corners((7, 3), (2, 3)), 6);
+    }
+
+    #[test]
+    fn part_1_example_test() {
+        assert_eq!(
+            find_max_area(&lines_to_points(example_lines()).unwrap()),
+            50
+        );
+    }
+
+    /// Small coordinates so that many points share a row or column.
+    fn scattered_points(count: usize, range: u64, seed: u64) -> Vec<(u64, u64)> {
+        let mut state = seed;
+        let mut next = || {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (state >> 33) % range
+        };
+        (0..count).map(|_| (next(), next())).collect()
+    }
+
+    #[test]
+    fn fast_max_area_matches_brute_force() {
+        let points = lines_to_points(example_lines()).unwrap();
+        assert_eq!(find_max_area_fast(&points), 50);
+        assert_eq!(find_max_area_fast(&[]), 0);
+        assert_eq!(find_max_area_fast(&[(3, 4)]), 0);
+        assert_eq!(find_max_area_fast(&[(3, 4), (3, 4)]), 1);
+
+        for seed in 0..200 {
+            let points = scattered_points(1 + seed as usize % 40, 3 + seed % 30, seed);
+            assert_eq!(
+                find_max_area_fast(&points),
+                find_max_area(&points),
+                "{points:?}"
+            );
+        }
+        let points = scattered_points(2000, 1_000_000, 7);
+        assert_eq!(find_max_area_fast(&points), find_max_area(&points));
+    }
+
+    #[test]
+    fn fast_max_area_handles_long_staircases() {
+        let mut points: Vec<(u64, u64)> = (0..300).map(|i| (i, 300 - i)).collect();
+        points.extend((0..300).map(|i| (1000 + i, 1600 - i * i / 200)));
+        points.extend((0..300).map(|i| (i * 7 % 300 + 400, 2000 + i * 13 % 300)));
+        assert_eq!(find_max_area_fast(&points), find_max_area(&points));
+    }
+
+    #[test]
+    fn part_2_example_test() {
+        let points = lines_to_points(example_lines()).unwrap();
+        assert_eq!(find_max_area_inside(&points).unwrap(), 24);
+
+        let polygon = CompressedPolygon::new(&points).unwrap();
+        assert!(polygon.contains_rectangle((7, 3), (11, 1)));
+        assert!(polygon.contains_rectangle((9, 7), (9, 5)));
+        assert!(!polygon.contains_rectangle((2, 5), (11, 1)));
+        assert!(!polygon.contains_rectangle((2, 3), (7, 1)));
+    }
+
+    #[test]
+    fn points_must_trace_a_rectilinear_polygon() {
+        let mut lines = example_lines();
+        lines[3] = "10,6".to_string();
+        let points = lines_to_points(lines.clone()).unwrap();
+        assert_eq!(
+            find_max_area_inside(&points).unwrap_err().to_string(),
+            "line 4, col 1: 10,6 is not in a row or column with 11,7"
+        );
+
+        let run = (DAY.run)(lines, &Params::new(), &[1, 2]).unwrap();
+        assert_eq!(run.parts[0].answer.as_ref().unwrap().as_deref(), Some("50"));
+        assert_eq!(
+            run.parts[1].answer.as_ref().unwrap_err().to_string(),
+            "line 4, col 1: 10,6 is not in a row or column with 11,7"
+        );
+    }
+}
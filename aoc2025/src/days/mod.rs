@@ -0,0 +1,12 @@
+//! One module per puzzle. Each has a `DAY` for the registry, a type implementing
+//! [`Solution`](crate::solution::Solution), and the public functions that solve it.
+
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
@@ -0,0 +1,124 @@
+//! The error type for everything that can go wrong reading, parsing or solving a puzzle.
+
+use std::fmt::{self, Display};
+use std::io;
+
+/// Anything that stops a puzzle being solved. Positions in the input are one-based, ready to
+/// show to the user.
+#[derive(Debug)]
+pub enum AocError {
+    /// A file could not be read or written.
+    Io {
+        /// The file that was being accessed.
+        path: String,
+        /// Why it failed.
+        error: io::Error,
+    },
+    /// A line of the input is not in the expected form.
+    Malformed {
+        /// The line, counting from 1.
+        line: usize,
+        /// The column, counting from 1.
+        column: usize,
+        /// What was expected and what was found.
+        message: String,
+    },
+    /// An operator the puzzle does not define.
+    UnsupportedOperation {
+        /// The line, counting from 1.
+        line: usize,
+        /// The column, counting from 1.
+        column: usize,
+        /// The operator found.
+        operation: char,
+    },
+    /// No input file was found in any of the places looked.
+    InputNotFound {
+        /// The name of the input file.
+        file: String,
+        /// Each path that was tried, in order.
+        tried: Vec<String>,
+    },
+    /// Anything else, such as a bad parameter or an input one part cannot use.
+    Invalid(String),
+}
+
+impl AocError {
+    /// An [`AocError::Io`] for `path`.
+    pub fn io(path: &str, error: io::Error) -> AocError {
+        AocError::Io {
+            path: path.to_string(),
+            error,
+        }
+    }
+
+    /// Takes zero-based indices, which are stored and reported one-based.
+    pub fn malformed(
+        line_index: usize,
+        column_index: usize,
+        message: impl Into<String>,
+    ) -> AocError {
+        AocError::Malformed {
+            line: line_index + 1,
+            column: column_index + 1,
+            message: message.into(),
+        }
+    }
+
+    /// Takes zero-based indices, which are stored and reported one-based.
+    pub fn unsupported_operation(
+        line_index: usize,
+        column_index: usize,
+        operation: char,
+    ) -> AocError {
+        AocError::UnsupportedOperation {
+            line: line_index + 1,
+            column: column_index + 1,
+            operation,
+        }
+    }
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::Io { path, error } => write!(f, "could not access '{path}': {error}"),
+            AocError::Malformed {
+                line,
+                column,
+                message,
+            } => write!(f, "line {line}, col {column}: {message}"),
+            AocError::UnsupportedOperation {
+                line,
+                column,
+                operation,
+            } => write!(
+                f,
+                "line {line}, col {column}: unknown operator '{operation}'"
+            ),
+            AocError::InputNotFound { file, tried } => {
+                write!(f, "could not find {file}, tried {}", tried.join(", "))
+            }
+            AocError::Invalid(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+impl std::error::Error for AocError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn positions_are_reported_one_based() {
+        assert_eq!(
+            AocError::unsupported_operation(3, 11, '-').to_string(),
+            "line 4, col 12: unknown operator '-'"
+        );
+        assert_eq!(
+            AocError::malformed(0, 0, "expected a number").to_string(),
+            "line 1, col 1: expected a number"
+        );
+    }
+}
@@ -0,0 +1,160 @@
+use crate::error::AocError;
+use crate::helpers;
+use crate::registry::{self, Day};
+use crate::solution::Params;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const EXAMPLES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../examples");
+
+/// An example input in `examples/dayNN/NAME.txt` with its sidecar `NAME.expected`. The
+/// sidecar holds `part1 = answer` and `part2 = answer` lines, and any other `key = value`
+/// line is a parameter the example needs, such as a smaller count than the real puzzle.
+#[derive(Debug, PartialEq)]
+pub struct Fixture {
+    pub lines: Vec<String>,
+    pub params: Params,
+    pub expected: BTreeMap<u32, String>,
+}
+
+impl Fixture {
+    pub fn path(day: u32, name: &str) -> PathBuf {
+        Path::new(EXAMPLES_DIR)
+            .join(format!("day{day:02}"))
+            .join(format!("{name}.txt"))
+    }
+
+    pub fn load(day: &Day, name: &str) -> Result<Fixture, AocError> {
+        let input = Fixture::path(day.number, name);
+        let sidecar = input.with_extension("expected");
+        let read = |path: &Path| {
+            fs::read_to_string(path).map_err(|e| AocError::io(&path.display().to_string(), e))
+        };
+        let contents = read(&input)?;
+        let (params, expected) = parse_expected(&read(&sidecar)?)
+            .map_err(|e| AocError::Invalid(format!("{}, {e}", sidecar.display())))?;
+        Ok(Fixture {
+            lines: helpers::split_contents(&contents, day.separator),
+            params,
+            expected,
+        })
+    }
+}
+
+pub fn parse_expected(contents: &str) -> Result<(Params, BTreeMap<u32, String>), AocError> {
+    let mut params = Params::new();
+    let mut expected = BTreeMap::new();
+    for (line, text) in contents.lines().enumerate() {
+        let text = text.trim();
+        if text.is_empty() || text.starts_with('#') {
+            continue;
+        }
+        let Some((key, value)) = text.split_once('=') else {
+            return Err(AocError::malformed(
+                line,
+                0,
+                format!("expected 'key = value', found '{text}'"),
+            ));
+        };
+        let column = text.len() - value.trim_start().len();
+        let (key, value) = (
+            key.trim(),
+            helpers::parse_value(value.trim(), line, column)?,
+        );
+        match key {
+            "part1" => expected.insert(1, value.to_string()),
+            "part2" => expected.insert(2, value.to_string()),
+            _ => {
+                params.set(key, value);
+                None
+            }
+        };
+    }
+    if expected.is_empty() {
+        return Err(AocError::Invalid(
+            "expected a part1 or part2 answer".to_string(),
+        ));
+    }
+    Ok((params, expected))
+}
+
+/// The input of the fixture `name` for `day`, split on the day's separator, for unit tests
+/// that need the example itself rather than its answers. The sidecar is not needed.
+pub fn example_lines(day: u32, name: &str) -> Vec<String> {
+    let day = registry::find_day(day).unwrap_or_else(|| panic!("day {day} is not registered"));
+    let path = Fixture::path(day.number, name);
+    let contents = fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {e}", path.display()));
+    helpers::split_contents(&contents, day.separator)
+}
+
+/// Runs the registered solution for `day` on the fixture `name` and checks each expected answer.
+pub fn check(day: u32, name: &str) {
+    let day = registry::find_day(day).unwrap_or_else(|| panic!("day {day} is not registered"));
+    let fixture = Fixture::load(day, name).unwrap_or_else(|e| panic!("day {}, {e}", day.number));
+    let parts: Vec<u32> = fixture.expected.keys().copied().collect();
+    let run = (day.run)(fixture.lines, &fixture.params, &parts)
+        .unwrap_or_else(|e| panic!("day {}, {name}: {e}", day.number));
+    for part in run.parts {
+        let answer = part
+            .answer
+            .unwrap_or_else(|e| panic!("day {} part {} on {name}: {e}", day.number, part.part));
+        assert_eq!(
+            answer.as_deref(),
+            Some(fixture.expected[&part.part].as_str()),
+            "day {} part {} on {name}",
+            day.number,
+            part.part
+        );
+    }
+}
+
+/// One `#[test]` per fixture, each written as `test_name: day, "fixture name";`. The build
+/// script generates the invocation from the files in `examples/`.
+macro_rules! example_tests {
+    ($($test:ident: $day:literal, $name:literal;)*) => {
+        $(
+            #[test]
+            fn $test() {
+                $crate::fixtures::check($day, $name);
+            }
+        )*
+    };
+}
+
+mod examples {
+    include!(concat!(env!("OUT_DIR"), "/example_tests.rs"));
+}
+
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_expected_splits_answers_from_params() {
+        let (params, expected) = parse_expected(
+            "# smaller than the puzzle\npairs = 10\n\npart1 = 40\npart2 = \"25272\"\n",
+        )
+        .unwrap();
+        let mut pairs = Params::new();
+        pairs.set("pairs", "10");
+        assert_eq!(params, pairs);
+        assert_eq!(
+            expected,
+            BTreeMap::from([(1, "40".to_string()), (2, "25272".to_string())])
+        );
+    }
+
+    #[test]
+    fn parse_expected_reports_bad_lines() {
+        assert_eq!(
+            parse_expected("part1 = 3\npart2\n")
+                .unwrap_err()
+                .to_string(),
+            "line 2, col 1: expected 'key = value', found 'part2'"
+        );
+        assert_eq!(
+            parse_expected("pairs = 10\n").unwrap_err().to_string(),
+            "expected a part1 or part2 answer"
+        );
+    }
+}
@@ -1,10 +1,828 @@
+//! Reading input, parsing numbers, and the grid, disjoint set and big integer types shared
+//! between days.
+
+use crate::error::AocError;
+use std::cmp::Ordering;
+use std::fmt::{self, Display};
 use std::fs::File;
 use std::io::Read;
+use std::iter::{Product, Sum};
+use std::ops::{Add, Index, IndexMut, Mul};
+use std::path::Path;
+use std::str::FromStr;
 
-pub fn get_file_separated_or_panic(file_name: &str, sep: &str) -> Vec<String> {
-    let mut file = File::open(file_name).expect("Invalid file");
+/// The file at `path`, split like [`split_contents`].
+pub fn get_file_separated(path: &Path, sep: &str) -> Result<Vec<String>, AocError> {
+    let file_name = path.display().to_string();
+    let mut file = File::open(path).map_err(|e| AocError::io(&file_name, e))?;
     let mut file_contents = String::new();
     file.read_to_string(&mut file_contents)
-        .expect("File could not be read.");
-    file_contents.split(sep).map(|e| e.trim_end_matches('\r').to_string()).collect()
+        .map_err(|e| AocError::io(&file_name, e))?;
+    Ok(split_contents(&file_contents, sep))
+}
+
+/// Everything on stdin, split like [`split_contents`].
+pub fn get_stdin_separated(sep: &str) -> Result<Vec<String>, AocError> {
+    let mut contents = String::new();
+    std::io::stdin()
+        .read_to_string(&mut contents)
+        .map_err(|e| AocError::io("<stdin>", e))?;
+    Ok(split_contents(&contents, sep))
+}
+
+/// Splits `contents` on `sep`, ignoring a line ending at the very end and a `\r` at the end of
+/// each piece, so files, stdin and fixtures split the same bytes the same way.
+pub fn split_contents(contents: &str, sep: &str) -> Vec<String> {
+    contents
+        .trim_end_matches(['\r', '\n'])
+        .split(sep)
+        .map(|e| e.trim_end_matches('\r').to_string())
+        .collect()
+}
+
+/// Parses `text` as a number, reporting `line` and `column` if it is not one.
+pub fn parse_number<T: FromStr>(text: &str, line: usize, column: usize) -> Result<T, AocError> {
+    text.parse().map_err(|_| {
+        AocError::malformed(line, column, format!("expected a number, found '{text}'"))
+    })
+}
+
+/// A quoted string without its quotes, or a bare word as it is.
+pub fn parse_value(value: &str, line: usize, column: usize) -> Result<&str, AocError> {
+    if let Some(quoted) = value.strip_prefix('"') {
+        return quoted.strip_suffix('"').ok_or(AocError::malformed(
+            line,
+            column,
+            format!("unterminated string {value}"),
+        ));
+    }
+    if value.is_empty() || value.contains(char::is_whitespace) {
+        return Err(AocError::malformed(
+            line,
+            column,
+            format!("expected a value, found '{value}'"),
+        ));
+    }
+    Ok(value)
+}
+
+/// Parses every `sep` separated field of a line as a number, reporting the column of a bad field.
+pub fn parse_separated_numbers<T: FromStr>(
+    text: &str,
+    sep: char,
+    line: usize,
+) -> Result<Vec<T>, AocError> {
+    let mut column = 0;
+    text.split(sep)
+        .map(|field| {
+            let number = parse_number(field, line, column);
+            column += field.chars().count() + 1;
+            number
+        })
+        .collect()
+}
+
+/// A grid position as `(x, y)`, with `y` counting rows down from the top.
+pub type Position = (usize, usize);
+
+const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+const SURROUNDING: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// A rectangular grid stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A grid of `cells` given row by row. Panics unless there are `width * height` of them.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Grid<T> {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "cell count must be width * height"
+        );
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Builds a grid from a character map, rejecting characters `map` returns `None` for and
+    /// rows that differ in length from the first.
+    pub fn parse(
+        lines: &[String],
+        mut map: impl FnMut(char) -> Option<T>,
+    ) -> Result<Grid<T>, AocError> {
+        let width = lines.first().map_or(0, |line| line.chars().count());
+        let mut cells = Vec::with_capacity(width * lines.len());
+        for (y, line) in lines.iter().enumerate() {
+            let mut row_width = 0;
+            for (x, c) in line.chars().enumerate() {
+                let cell = map(c).ok_or(AocError::malformed(
+                    y,
+                    x,
+                    format!("unexpected character '{c}'"),
+                ))?;
+                cells.push(cell);
+                row_width += 1;
+            }
+            if row_width != width {
+                return Err(AocError::malformed(
+                    y,
+                    row_width.min(width),
+                    format!("expected {width} cells, found {row_width}"),
+                ));
+            }
+        }
+        Ok(Grid::new(width, lines.len(), cells))
+    }
+
+    /// The number of columns.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// The number of rows.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The cell at `(x, y)`, or `None` outside the grid.
+    pub fn get(&self, (x, y): Position) -> Option<&T> {
+        (x < self.width && y < self.height).then(|| &self.cells[y * self.width + x])
+    }
+
+    /// The cell at `(x, y)` to change, or `None` outside the grid.
+    pub fn get_mut(&mut self, (x, y): Position) -> Option<&mut T> {
+        (x < self.width && y < self.height).then(|| &mut self.cells[y * self.width + x])
+    }
+
+    /// Every position in reading order.
+    pub fn positions(&self) -> impl Iterator<Item = Position> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// The positions sharing an edge with `position`, within the grid.
+    pub fn neighbors4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        self.offset_positions(position, &ORTHOGONAL)
+    }
+
+    /// The positions sharing an edge or a corner with `position`, within the grid.
+    pub fn neighbors8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        self.offset_positions(position, &SURROUNDING)
+    }
+
+    fn offset_positions<'a>(
+        &'a self,
+        (x, y): Position,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = Position> + 'a {
+        offsets.iter().filter_map(move |&(dx, dy)| {
+            let x = x.checked_add_signed(dx).filter(|x| *x < self.width)?;
+            let y = y.checked_add_signed(dy).filter(|y| *y < self.height)?;
+            Some((x, y))
+        })
+    }
+
+    /// Row `y`, or `None` below the grid.
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.height).then(|| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    /// Every row, top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    /// The cells of column `x`, top to bottom, or none if `x` is past the right edge.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        let cells = if x < self.width {
+            &self.cells[x..]
+        } else {
+            &[]
+        };
+        cells.iter().step_by(self.width.max(1))
+    }
+
+    /// Every column, left to right.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// The first position holding `value`, in reading order.
+    pub fn find(&self, value: &T) -> Option<Position>
+    where
+        T: PartialEq,
+    {
+        self.find_all(value).next()
+    }
+
+    /// Every position holding `value`, in reading order.
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Position> + 'a
+    where
+        T: PartialEq,
+    {
+        self.positions()
+            .filter(move |&position| self[position] == *value)
+    }
+
+    /// A grid of the same shape with `f` applied to each cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        self.get(position)
+            .unwrap_or_else(|| panic!("{position:?} is outside the grid"))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{position:?} is outside the grid"))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Two components joined by [`DisjointSet::union`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Merge {
+    /// The elements passed to `union`.
+    pub pair: (usize, usize),
+    /// The root of the joined component.
+    pub root: usize,
+    /// The size of the joined component.
+    pub size: usize,
+    /// The number of components left after the merge.
+    pub components: usize,
+}
+
+/// Union-find over the elements `0..len`, with path compression and union by size.
+#[derive(Debug, Clone)]
+pub struct DisjointSet {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    components: usize,
+}
+
+impl DisjointSet {
+    /// Each of the elements `0..len` in a component of its own.
+    pub fn new(len: usize) -> DisjointSet {
+        DisjointSet {
+            parent: (0..len).collect(),
+            size: vec![1; len],
+            components: len,
+        }
+    }
+
+    /// The number of elements.
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    /// Whether there are no elements.
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// The root of the component holding `element`.
+    pub fn find(&mut self, element: usize) -> usize {
+        let mut root = element;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        let mut current = element;
+        while self.parent[current] != root {
+            current = std::mem::replace(&mut self.parent[current], root);
+        }
+        root
+    }
+
+    /// Joins the components holding `a` and `b`, or returns `None` if they already share one.
+    pub fn union(&mut self, a: usize, b: usize) -> Option<Merge> {
+        let (mut root, mut absorbed) = (self.find(a), self.find(b));
+        if root == absorbed {
+            return None;
+        }
+        if self.size[root] < self.size[absorbed] {
+            (root, absorbed) = (absorbed, root);
+        }
+        self.parent[absorbed] = root;
+        self.size[root] += self.size[absorbed];
+        self.components -= 1;
+        Some(Merge {
+            pair: (a, b),
+            root,
+            size: self.size[root],
+            components: self.components,
+        })
+    }
+
+    /// Unions each pair in turn, yielding only the pairs that joined two components.
+    pub fn merges<'a>(
+        &'a mut self,
+        pairs: impl IntoIterator<Item = (usize, usize)> + 'a,
+    ) -> impl Iterator<Item = Merge> + 'a {
+        pairs.into_iter().filter_map(|(a, b)| self.union(a, b))
+    }
+
+    /// Whether `a` and `b` are in the same component.
+    pub fn same_component(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// The number of components.
+    pub fn component_count(&self) -> usize {
+        self.components
+    }
+
+    /// The size of the component holding `element`.
+    pub fn component_size(&mut self, element: usize) -> usize {
+        let root = self.find(element);
+        self.size[root]
+    }
+
+    /// The size of every component, in order of their roots.
+    pub fn sizes(&self) -> Vec<usize> {
+        (0..self.len())
+            .filter(|&element| self.parent[element] == element)
+            .map(|root| self.size[root])
+            .collect()
+    }
+
+    /// The sizes of the `k` largest components, largest first.
+    pub fn largest(&self, k: usize) -> Vec<usize> {
+        let mut sizes = self.sizes();
+        sizes.sort_unstable_by(|a, b| b.cmp(a));
+        sizes.truncate(k);
+        sizes
+    }
+}
+
+const LIMB_BASE: u64 = 1_000_000_000;
+const LIMB_DIGITS: usize = 9;
+
+/// An arbitrary-precision unsigned integer, for answers that overflow `u128`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BigUint {
+    // Base 10^9 digits, least significant first, with no trailing zeros.
+    limbs: Vec<u32>,
+}
+
+impl BigUint {
+    /// The number 0.
+    pub fn zero() -> BigUint {
+        BigUint::default()
+    }
+
+    /// Whether the number is 0.
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    fn trim(mut self) -> BigUint {
+        while self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+        self
+    }
+}
+
+impl From<u128> for BigUint {
+    fn from(mut value: u128) -> BigUint {
+        let mut limbs = Vec::new();
+        while value > 0 {
+            limbs.push((value % LIMB_BASE as u128) as u32);
+            value /= LIMB_BASE as u128;
+        }
+        BigUint { limbs }
+    }
+}
+
+impl FromStr for BigUint {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<BigUint, String> {
+        if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(format!("expected digits, found '{s}'"));
+        }
+        let limbs = s
+            .as_bytes()
+            .rchunks(LIMB_DIGITS)
+            .map(|chunk| {
+                chunk
+                    .iter()
+                    .fold(0, |limb, digit| limb * 10 + u32::from(digit - b'0'))
+            })
+            .collect();
+        Ok(BigUint { limbs }.trim())
+    }
+}
+
+impl Display for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some((last, rest)) = self.limbs.split_last() else {
+            return f.pad("0");
+        };
+        let mut text = last.to_string();
+        for limb in rest.iter().rev() {
+            text.push_str(&format!("{limb:09}"));
+        }
+        f.pad(&text)
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &BigUint) -> Ordering {
+        self.limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &BigUint) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Add<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn add(self, other: &BigUint) -> BigUint {
+        let mut limbs = Vec::with_capacity(self.limbs.len().max(other.limbs.len()) + 1);
+        let mut carry = 0;
+        for i in 0..self.limbs.len().max(other.limbs.len()) {
+            let total = u64::from(*self.limbs.get(i).unwrap_or(&0))
+                + u64::from(*other.limbs.get(i).unwrap_or(&0))
+                + carry;
+            limbs.push((total % LIMB_BASE) as u32);
+            carry = total / LIMB_BASE;
+        }
+        if carry > 0 {
+            limbs.push(carry as u32);
+        }
+        BigUint { limbs }
+    }
+}
+
+impl Add for BigUint {
+    type Output = BigUint;
+
+    fn add(self, other: BigUint) -> BigUint {
+        &self + &other
+    }
+}
+
+impl Mul<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn mul(self, other: &BigUint) -> BigUint {
+        if self.is_zero() || other.is_zero() {
+            return BigUint::zero();
+        }
+        let mut limbs = vec![0u64; self.limbs.len() + other.limbs.len()];
+        for (i, &a) in self.limbs.iter().enumerate() {
+            let mut carry = 0;
+            for (j, &b) in other.limbs.iter().enumerate() {
+                let total = limbs[i + j] + u64::from(a) * u64::from(b) + carry;
+                limbs[i + j] = total % LIMB_BASE;
+                carry = total / LIMB_BASE;
+            }
+            limbs[i + other.limbs.len()] += carry;
+        }
+        BigUint {
+            limbs: limbs.into_iter().map(|limb| limb as u32).collect(),
+        }
+        .trim()
+    }
+}
+
+impl Mul for BigUint {
+    type Output = BigUint;
+
+    fn mul(self, other: BigUint) -> BigUint {
+        &self * &other
+    }
+}
+
+impl Sum for BigUint {
+    fn sum<I: Iterator<Item = BigUint>>(iter: I) -> BigUint {
+        iter.fold(BigUint::zero(), |total, value| total + value)
+    }
+}
+
+impl Product for BigUint {
+    fn product<I: Iterator<Item = BigUint>>(iter: I) -> BigUint {
+        iter.fold(BigUint::from(1), |total, value| total * value)
+    }
+}
+
+/// What an aggregation like a sum of answers can be computed in. The checked operations
+/// return `None` when the result does not fit, which only happens for `u128`, or when a
+/// subtraction would go below zero.
+pub trait Number:
+    From<u128> + Add<Output = Self> + Mul<Output = Self> + Sum + Product + Display
+{
+    /// `self + other`, or `None` if it does not fit.
+    fn checked_add(self, other: Self) -> Option<Self>;
+    /// `self - other`, or `None` if `other` is larger.
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    /// `self * other`, or `None` if it does not fit.
+    fn checked_mul(self, other: Self) -> Option<Self>;
+}
+
+impl Number for u128 {
+    fn checked_add(self, other: u128) -> Option<u128> {
+        u128::checked_add(self, other)
+    }
+
+    fn checked_sub(self, other: u128) -> Option<u128> {
+        u128::checked_sub(self, other)
+    }
+
+    fn checked_mul(self, other: u128) -> Option<u128> {
+        u128::checked_mul(self, other)
+    }
+}
+
+impl Number for BigUint {
+    fn checked_add(self, other: BigUint) -> Option<BigUint> {
+        Some(self + other)
+    }
+
+    fn checked_sub(self, other: BigUint) -> Option<BigUint> {
+        if other > self {
+            return None;
+        }
+        let mut limbs = Vec::with_capacity(self.limbs.len());
+        let mut borrow = 0;
+        for (i, &limb) in self.limbs.iter().enumerate() {
+            let subtrahend = i64::from(*other.limbs.get(i).unwrap_or(&0)) + borrow;
+            let mut difference = i64::from(limb) - subtrahend;
+            borrow = i64::from(difference < 0);
+            if difference < 0 {
+                difference += LIMB_BASE as i64;
+            }
+            limbs.push(difference as u32);
+        }
+        Some(BigUint { limbs }.trim())
+    }
+
+    fn checked_mul(self, other: BigUint) -> Option<BigUint> {
+        Some(self * other)
+    }
+}
+
+/// The error for an answer too large for `u128`, pointing at `arithmetic=big`.
+pub fn too_large(what: &str) -> AocError {
+    AocError::Invalid(format!(
+        "{what} does not fit in 128 bits, try arithmetic=big"
+    ))
+}
+
+/// Whether a day totals its answers in `u128` or in [`BigUint`].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Arithmetic {
+    /// Plain `u128`, given as `native`.
+    #[default]
+    Native,
+    /// [`BigUint`], given as `big`.
+    Big,
+}
+
+impl FromStr for Arithmetic {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Arithmetic, String> {
+        match s {
+            "native" => Ok(Arithmetic::Native),
+            "big" => Ok(Arithmetic::Big),
+            _ => Err(format!("expected native or big, found '{s}'")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(text: &str) -> Vec<String> {
+        text.lines().map(|line| line.to_string()).collect()
+    }
+
+    fn char_grid(text: &str) -> Grid<char> {
+        Grid::parse(&lines(text), Some).unwrap()
+    }
+
+    #[test]
+    fn files_ending_in_a_newline_split_like_stdin() {
+        let path = std::env::temp_dir().join(format!("aoc2025-helpers-{}.txt", std::process::id()));
+        std::fs::write(&path, "L68\r\nR48\r\n").unwrap();
+        let lines = get_file_separated(&path, "\n").unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(lines, vec!["L68", "R48"]);
+        assert_eq!(split_contents("1-2,3-4\n", ","), vec!["1-2", "3-4"]);
+        assert_eq!(split_contents("3-5\n\n1\n", "\n"), vec!["3-5", "", "1"]);
+    }
+
+    #[test]
+    fn parse_and_display_round_trip() {
+        let grid = char_grid("..@\n@.S");
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid.get((2, 0)), Some(&'@'));
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.to_string(), "..@\n@.S");
+    }
+
+    #[test]
+    fn parse_rejects_unmapped_characters_and_ragged_rows() {
+        let paper = |c| match c {
+            '@' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        };
+        assert_eq!(
+            Grid::parse(&lines(".@\n@x"), paper)
+                .unwrap_err()
+                .to_string(),
+            "line 2, col 2: unexpected character 'x'"
+        );
+        assert_eq!(
+            Grid::parse(&lines(".@.\n@."), paper)
+                .unwrap_err()
+                .to_string(),
+            "line 2, col 3: expected 3 cells, found 2"
+        );
+    }
+
+    #[test]
+    fn neighbors8_works_for_corners() {
+        let grid = Grid::new(5, 5, vec![0; 25]);
+        let mut found_neighbors: Vec<Position> = grid.neighbors8((4, 4)).collect();
+        found_neighbors.sort();
+
+        let mut actual = vec![(4, 3), (3, 3), (3, 4)];
+        actual.sort();
+
+        assert_eq!(actual, found_neighbors);
+        assert_eq!(grid.neighbors8((2, 2)).count(), 8);
+    }
+
+    #[test]
+    fn neighbors4_skips_diagonals_and_edges() {
+        let grid = Grid::new(3, 2, vec![0; 6]);
+        let neighbors: Vec<Position> = grid.neighbors4((0, 0)).collect();
+        assert_eq!(neighbors, vec![(1, 0), (0, 1)]);
+    }
+
+    #[test]
+    fn rows_columns_and_find() {
+        let mut grid = char_grid("S.^\n.^.");
+        let columns: Vec<String> = grid.columns().map(|column| column.collect()).collect();
+        assert_eq!(columns, vec!["S.", ".^", "^."]);
+        assert_eq!(grid.row(1), Some(&['.', '^', '.'][..]));
+        assert_eq!(grid.find(&'S'), Some((0, 0)));
+        assert_eq!(
+            grid.find_all(&'^').collect::<Vec<_>>(),
+            vec![(2, 0), (1, 1)]
+        );
+
+        *grid.get_mut((0, 0)).unwrap() = '.';
+        grid[(1, 1)] = '.';
+        assert_eq!(grid.find(&'S'), None);
+        assert_eq!(grid.map(|c| *c == '^').find_all(&true).count(), 1);
+    }
+
+    #[test]
+    fn parse_separated_numbers_test() {
+        assert_eq!(
+            parse_separated_numbers::<u64>("7,1", ',', 0).unwrap(),
+            vec![7, 1]
+        );
+        assert_eq!(
+            parse_separated_numbers::<u64>("12,x4,5", ',', 2)
+                .unwrap_err()
+                .to_string(),
+            "line 3, col 4: expected a number, found 'x4'"
+        );
+    }
+
+    #[test]
+    fn disjoint_set_tracks_components() {
+        let mut set = DisjointSet::new(6);
+        assert_eq!(set.component_count(), 6);
+        assert_eq!(set.union(0, 1).map(|m| m.size), Some(2));
+        assert_eq!(set.union(2, 3).map(|m| m.components), Some(4));
+        let merge = set.union(1, 3).unwrap();
+        assert_eq!((merge.pair, merge.size, merge.components), ((1, 3), 4, 3));
+        assert_eq!(set.union(0, 2), None);
+
+        assert!(set.same_component(0, 3));
+        assert!(!set.same_component(0, 4));
+        assert_eq!(set.component_size(2), 4);
+        assert_eq!(set.largest(2), vec![4, 1]);
+        let mut sizes = set.sizes();
+        sizes.sort();
+        assert_eq!(sizes, vec![1, 1, 4]);
+    }
+
+    #[test]
+    fn merges_skips_pairs_already_joined() {
+        let mut set = DisjointSet::new(4);
+        let merges: Vec<Merge> = set.merges([(0, 1), (1, 0), (2, 3), (0, 3)]).collect();
+        let pairs: Vec<(usize, usize)> = merges.iter().map(|m| m.pair).collect();
+        assert_eq!(pairs, vec![(0, 1), (2, 3), (0, 3)]);
+        assert_eq!(merges.last().map(|m| m.components), Some(1));
+    }
+
+    #[test]
+    fn big_uint_parses_and_displays() {
+        for text in [
+            "0",
+            "7",
+            "1000000000",
+            "340282366920938463463374607431768211456",
+        ] {
+            assert_eq!(text.parse::<BigUint>().unwrap().to_string(), text);
+        }
+        assert_eq!("000123".parse::<BigUint>().unwrap(), BigUint::from(123));
+        assert_eq!(format!("{:>5}", BigUint::from(42)), "   42");
+        assert!("12a".parse::<BigUint>().is_err());
+        assert!("".parse::<BigUint>().is_err());
+    }
+
+    #[test]
+    fn big_uint_arithmetic_matches_u128() {
+        let values = [
+            0,
+            1,
+            999_999_999,
+            1_000_000_000,
+            123_456_789_012_345,
+            u64::MAX as u128,
+        ];
+        for a in values {
+            for b in values {
+                let (big_a, big_b) = (BigUint::from(a), BigUint::from(b));
+                assert_eq!(&big_a + &big_b, BigUint::from(a + b));
+                assert_eq!(&big_a * &big_b, BigUint::from(a * b));
+                assert_eq!(big_a.cmp(&big_b), a.cmp(&b));
+                assert_eq!(
+                    Number::checked_sub(big_a.clone(), big_b.clone()),
+                    a.checked_sub(b).map(BigUint::from)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn big_uint_goes_past_u128() {
+        let max = BigUint::from(u128::MAX);
+        assert_eq!(
+            (&max + &BigUint::from(1)).to_string(),
+            "340282366920938463463374607431768211456"
+        );
+        assert_eq!(
+            (&max * &max).to_string(),
+            "115792089237316195423570985008687907852589419931798687112530834793049593217025"
+        );
+        let factorial: BigUint = (1..=30).map(BigUint::from).product();
+        assert_eq!(factorial.to_string(), "265252859812191058636308480000000");
+        assert!(factorial < max);
+    }
 }
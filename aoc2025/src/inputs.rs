@@ -0,0 +1,113 @@
+use crate::config::{Config, WORKSPACE_ROOT};
+use aoc2025::error::AocError;
+use std::env;
+use std::path::{Path, PathBuf};
+
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// The directories searched for puzzle inputs, in the order they are tried.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InputDirs {
+    dirs: Vec<PathBuf>,
+}
+
+impl InputDirs {
+    /// The `--input-dir` flag, then the `AOC_INPUT_DIR` variable, then `input_dir` from
+    /// `aoc.toml`, then the default directory, first under the current directory and then
+    /// under the workspace root.
+    pub fn new(
+        flag: Option<&Path>,
+        env: Option<&Path>,
+        config: Option<&Path>,
+        default: &str,
+    ) -> InputDirs {
+        let mut dirs: Vec<PathBuf> = Vec::new();
+        let workspace_default = Path::new(WORKSPACE_ROOT).join(default);
+        for dir in [
+            flag,
+            env,
+            config,
+            Some(Path::new(default)),
+            Some(&workspace_default),
+        ]
+        .into_iter()
+        .flatten()
+        {
+            if !dirs.iter().any(|known| known == dir) {
+                dirs.push(dir.to_path_buf());
+            }
+        }
+        InputDirs { dirs }
+    }
+
+    /// Looks up the environment variable for the directory after `flag`.
+    pub fn load(flag: Option<&str>, config: &Config) -> InputDirs {
+        let env = env::var_os(INPUT_DIR_VAR).map(PathBuf::from);
+        InputDirs::new(
+            flag.map(Path::new),
+            env.as_deref(),
+            config.input_dir.as_deref(),
+            &config.default_input_dir(),
+        )
+    }
+
+    /// The first directory holding `file_name`, joined with it.
+    pub fn resolve(&self, file_name: &str) -> Result<PathBuf, AocError> {
+        let tried: Vec<PathBuf> = self.dirs.iter().map(|dir| dir.join(file_name)).collect();
+        match tried.iter().find(|path| path.is_file()) {
+            Some(path) => Ok(path.clone()),
+            None => Err(AocError::InputNotFound {
+                file: file_name.to_string(),
+                tried: tried
+                    .iter()
+                    .map(|path| path.display().to_string())
+                    .collect(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn resolve_takes_the_first_directory_with_the_file() {
+        let root = env::temp_dir().join(format!("aoc2025-inputs-{}", std::process::id()));
+        let (flag, from_env) = (root.join("flag"), root.join("env"));
+        fs::create_dir_all(&flag).unwrap();
+        fs::create_dir_all(&from_env).unwrap();
+        fs::write(flag.join("day01.txt"), "L1").unwrap();
+        fs::write(from_env.join("day01.txt"), "R1").unwrap();
+        fs::write(from_env.join("day02.txt"), "1-2").unwrap();
+
+        let dirs = InputDirs::new(Some(&flag), Some(&from_env), None, "inputs/2025");
+        assert_eq!(dirs.resolve("day01.txt").unwrap(), flag.join("day01.txt"));
+        assert_eq!(
+            dirs.resolve("day02.txt").unwrap(),
+            from_env.join("day02.txt")
+        );
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn missing_input_lists_every_path_tried() {
+        let dirs = InputDirs::new(
+            Some(Path::new("flag")),
+            Some(Path::new("env")),
+            Some(Path::new("flag")),
+            "inputs/2025",
+        );
+        let error = dirs.resolve("day26.txt").unwrap_err().to_string();
+        let workspace = Path::new(WORKSPACE_ROOT).join("inputs/2025/day26.txt");
+        assert_eq!(
+            error,
+            format!(
+                "could not find day26.txt, tried flag/day26.txt, env/day26.txt, \
+                 inputs/2025/day26.txt, {}",
+                workspace.display()
+            )
+        );
+    }
+}
@@ -0,0 +1,353 @@
+//! Sets of integers kept as sorted, disjoint inclusive ranges.
+
+use std::cmp::Ordering;
+
+/// An inclusive range of values, `lower..=upper`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Range {
+    lower: u64,
+    upper: u64,
+}
+
+impl Range {
+    /// The range `lower..=upper`. Panics if `lower` is above `upper`.
+    pub fn new(lower: u64, upper: u64) -> Range {
+        assert!(
+            lower <= upper,
+            "range starts at {lower} but ends at {upper}"
+        );
+        Range { lower, upper }
+    }
+
+    /// The smallest value in the range.
+    pub fn lower(&self) -> u64 {
+        self.lower
+    }
+
+    /// The largest value in the range.
+    pub fn upper(&self) -> u64 {
+        self.upper
+    }
+
+    /// The number of values in the range, as a `u128` since `0..=u64::MAX` holds `2^64`.
+    pub fn size(&self) -> u128 {
+        u128::from(self.upper - self.lower) + 1
+    }
+
+    /// Whether `value` is in the range.
+    pub fn contains(&self, value: u64) -> bool {
+        self.lower <= value && value <= self.upper
+    }
+}
+
+#[derive(PartialEq, Eq, Debug)]
+enum RangePoint {
+    Start(u64),
+    End(u64),
+}
+
+impl PartialOrd<Self> for RangePoint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for RangePoint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let left = value_in_range_point(self);
+        let right = value_in_range_point(other);
+        match left.cmp(&right) {
+            Ordering::Less => Ordering::Less,
+            Ordering::Greater => Ordering::Greater,
+            Ordering::Equal => match (self, other) {
+                (RangePoint::Start(l_val), RangePoint::Start(r_val))
+                | (RangePoint::End(l_val), RangePoint::End(r_val)) => l_val.cmp(r_val),
+                (RangePoint::Start(_), RangePoint::End(_)) => Ordering::Less,
+                (RangePoint::End(_), RangePoint::Start(_)) => Ordering::Greater,
+            },
+        }
+    }
+}
+
+fn value_in_range_point(point: &RangePoint) -> u64 {
+    match point {
+        RangePoint::Start(val) => *val,
+        RangePoint::End(val) => *val,
+    }
+}
+
+fn ranges_to_sorted_points(ranges: &[Range]) -> Vec<RangePoint> {
+    let mut list: Vec<RangePoint> = ranges
+        .iter()
+        .flat_map(|r| [RangePoint::Start(r.lower), RangePoint::End(r.upper)])
+        .collect();
+    list.sort();
+    list
+}
+
+fn combine_ranges(range: &[Range]) -> Vec<Range> {
+    let sorted_points = ranges_to_sorted_points(range);
+    let mut open_count = 0;
+    let mut current_start: Option<u64> = None;
+    let mut found_ranges: Vec<Range> = Vec::new();
+
+    for point in sorted_points {
+        match point {
+            RangePoint::Start(start_val) => {
+                open_count += 1;
+                if current_start.is_none() {
+                    current_start = Some(start_val)
+                }
+            }
+            RangePoint::End(end_val) => {
+                open_count -= 1;
+                if open_count == 0 {
+                    let start_val =
+                        current_start.expect("Should never have an End without a start");
+                    found_ranges.push(Range::new(start_val, end_val));
+                    current_start = None;
+                }
+            }
+        }
+    }
+    found_ranges
+}
+
+/// A set of values stored as sorted, disjoint ranges. Ranges that touch are joined, so
+/// `3-5` and `6-8` are kept as `3-8`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntervalSet {
+    ranges: Vec<Range>,
+}
+
+impl IntervalSet {
+    /// The empty set.
+    pub fn new() -> IntervalSet {
+        IntervalSet::default()
+    }
+
+    /// Builds a set from ranges that may overlap, using a sweep over their start and end points.
+    pub fn merge(ranges: &[Range]) -> IntervalSet {
+        let mut joined: Vec<Range> = Vec::new();
+        for range in combine_ranges(ranges) {
+            match joined.last_mut() {
+                Some(last) if last.upper.checked_add(1) == Some(range.lower) => {
+                    last.upper = range.upper
+                }
+                _ => joined.push(range),
+            }
+        }
+        IntervalSet { ranges: joined }
+    }
+
+    /// The ranges in increasing order, none of them touching.
+    pub fn ranges(&self) -> &[Range] {
+        &self.ranges
+    }
+
+    /// Whether the set has no values.
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Adds every value in `range`, joining it with the ranges it overlaps or touches.
+    pub fn insert(&mut self, range: Range) {
+        let first = self
+            .ranges
+            .partition_point(|r| r.upper.saturating_add(1) < range.lower);
+        let last = self
+            .ranges
+            .partition_point(|r| r.lower <= range.upper.saturating_add(1));
+        let touching = &self.ranges[first..last];
+        let merged = match (touching.first(), touching.last()) {
+            (Some(head), Some(tail)) => {
+                Range::new(head.lower.min(range.lower), tail.upper.max(range.upper))
+            }
+            _ => range,
+        };
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    /// Whether `value` is in the set, found by binary search.
+    pub fn contains(&self, value: u64) -> bool {
+        let index = self.ranges.partition_point(|r| r.upper < value);
+        self.ranges
+            .get(index)
+            .is_some_and(|range| range.contains(value))
+    }
+
+    /// The number of values covered by the set, which may be every `u64`.
+    pub fn size(&self) -> u128 {
+        self.ranges.iter().map(Range::size).sum()
+    }
+
+    /// The values in either set.
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        let all: Vec<Range> = self.ranges.iter().chain(&other.ranges).copied().collect();
+        IntervalSet::merge(&all)
+    }
+
+    /// The values in both sets.
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while let (Some(left), Some(right)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let lower = left.lower.max(right.lower);
+            let upper = left.upper.min(right.upper);
+            if lower <= upper {
+                ranges.push(Range::new(lower, upper));
+            }
+            if left.upper < right.upper {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    /// The values in this set but not in `other`.
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        match (self.ranges.first(), self.ranges.last()) {
+            (Some(first), Some(last)) => {
+                self.intersection(&other.complement(Range::new(first.lower, last.upper)))
+            }
+            _ => IntervalSet::new(),
+        }
+    }
+
+    /// The values within `bounds` that are not in the set.
+    pub fn complement(&self, bounds: Range) -> IntervalSet {
+        let mut ranges = Vec::new();
+        let mut next_lower = Some(bounds.lower);
+        for range in &self.ranges {
+            let Some(lower) = next_lower else { break };
+            if range.upper < lower {
+                continue;
+            }
+            if range.lower > bounds.upper {
+                break;
+            }
+            if range.lower > lower {
+                ranges.push(Range::new(lower, range.lower - 1));
+            }
+            next_lower = range.upper.checked_add(1);
+        }
+        if let Some(lower) = next_lower
+            && lower <= bounds.upper
+        {
+            ranges.push(Range::new(lower, bounds.upper));
+        }
+        IntervalSet { ranges }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_ranges() -> Vec<Range> {
+        vec![
+            Range::new(3, 5),
+            Range::new(4, 4),
+            Range::new(10, 14),
+            Range::new(16, 20),
+            Range::new(12, 18),
+            Range::new(20, 20),
+        ]
+    }
+
+    fn sorted_range_points() -> Vec<RangePoint> {
+        vec![
+            RangePoint::Start(3),
+            RangePoint::Start(4),
+            RangePoint::End(4),
+            RangePoint::End(5),
+            RangePoint::Start(10),
+            RangePoint::Start(12),
+            RangePoint::End(14),
+            RangePoint::Start(16),
+            RangePoint::End(18),
+            RangePoint::Start(20),
+            RangePoint::End(20),
+            RangePoint::End(20),
+        ]
+    }
+
+    fn set(ranges: &[(u64, u64)]) -> IntervalSet {
+        let ranges: Vec<Range> = ranges.iter().map(|&(l, u)| Range::new(l, u)).collect();
+        IntervalSet::merge(&ranges)
+    }
+
+    #[test]
+    fn ranges_get_turned_to_range_points_than_sort_correctly() {
+        let sorted = ranges_to_sorted_points(&test_ranges());
+        assert_eq!(sorted, sorted_range_points())
+    }
+
+    #[test]
+    fn combine_ranges_test() {
+        assert_eq!(
+            combine_ranges(&test_ranges()),
+            vec![Range::new(3, 5), Range::new(10, 20)]
+        )
+    }
+
+    #[test]
+    fn merge_joins_touching_ranges() {
+        assert_eq!(
+            set(&[(6, 8), (3, 5), (10, 10)]).ranges(),
+            set(&[(3, 8), (10, 10)]).ranges()
+        );
+        assert_eq!(IntervalSet::merge(&test_ranges()).size(), 14);
+    }
+
+    #[test]
+    fn size_counts_every_u64() {
+        assert_eq!(Range::new(0, u64::MAX).size(), 1 << 64);
+        assert_eq!(Range::new(u64::MAX, u64::MAX).size(), 1);
+        assert_eq!(set(&[(0, 5), (6, u64::MAX)]).size(), 1 << 64);
+        assert_eq!(set(&[(0, 0), (2, u64::MAX)]).size(), u128::from(u64::MAX));
+        assert_eq!(IntervalSet::new().size(), 0);
+    }
+
+    #[test]
+    fn insert_matches_merge() {
+        let mut inserted = IntervalSet::new();
+        for range in test_ranges() {
+            inserted.insert(range);
+        }
+        assert_eq!(inserted, IntervalSet::merge(&test_ranges()));
+
+        inserted.insert(Range::new(6, 9));
+        assert_eq!(inserted, set(&[(3, 20)]));
+        inserted.insert(Range::new(u64::MAX, u64::MAX));
+        assert_eq!(inserted, set(&[(3, 20), (u64::MAX, u64::MAX)]));
+    }
+
+    #[test]
+    fn contains_uses_covered_ranges() {
+        let fresh = IntervalSet::merge(&test_ranges());
+        let found: Vec<u64> = [1, 5, 8, 11, 17, 32]
+            .into_iter()
+            .filter(|id| fresh.contains(*id))
+            .collect();
+        assert_eq!(found, vec![5, 11, 17]);
+    }
+
+    #[test]
+    fn set_operations() {
+        let left = set(&[(1, 5), (10, 15)]);
+        let right = set(&[(4, 11), (20, 25)]);
+        assert_eq!(left.union(&right), set(&[(1, 15), (20, 25)]));
+        assert_eq!(left.intersection(&right), set(&[(4, 5), (10, 11)]));
+        assert_eq!(left.difference(&right), set(&[(1, 3), (12, 15)]));
+        assert_eq!(right.difference(&left), set(&[(6, 9), (20, 25)]));
+        assert_eq!(left.complement(Range::new(0, 12)), set(&[(0, 0), (6, 9)]));
+        assert_eq!(
+            set(&[(0, u64::MAX)]).complement(Range::new(0, u64::MAX)),
+            IntervalSet::new()
+        );
+        assert_eq!(IntervalSet::new().difference(&left), IntervalSet::new());
+    }
+}
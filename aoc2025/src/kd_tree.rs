@@ -0,0 +1,335 @@
+//! Nearest-neighbour queries over 3D points, for streaming the closest pairs and building
+//! the minimum spanning tree.
+
+use crate::helpers::DisjointSet;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// Points with equal square distance are ordered by their indices, so every query has a single
+/// answer and the pair stream is deterministic.
+pub type Edge = (i64, usize, usize);
+
+fn square_dist(a: [i64; 3], b: [i64; 3]) -> i64 {
+    (0..3).map(|axis| (a[axis] - b[axis]).pow(2)).sum()
+}
+
+fn edge(square_dist: i64, a: usize, b: usize) -> Edge {
+    (square_dist, a.min(b), a.max(b))
+}
+
+/// A static k-d tree over 3D points, referring to each point by its index in the input slice.
+///
+/// The tree is stored implicitly: the node for a slice `lo..hi` of `order` sits at its middle,
+/// with its children in the halves either side.
+pub struct KdTree {
+    points: Vec<[i64; 3]>,
+    order: Vec<usize>,
+    max_index: Vec<usize>,
+}
+
+impl KdTree {
+    /// Builds the tree over `points`, splitting on each axis in turn.
+    pub fn new(points: &[[i64; 3]]) -> KdTree {
+        let mut order: Vec<usize> = (0..points.len()).collect();
+        build(points, &mut order, 0);
+        let mut tree = KdTree {
+            points: points.to_vec(),
+            order,
+            max_index: vec![0; points.len()],
+        };
+        tree.annotate_max_index(0, points.len());
+        tree
+    }
+
+    /// The number of points in the tree.
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Whether the tree has no points.
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    fn annotate_max_index(&mut self, lo: usize, hi: usize) -> usize {
+        if lo >= hi {
+            return 0;
+        }
+        let mid = lo + (hi - lo) / 2;
+        let max = self.order[mid]
+            .max(self.annotate_max_index(lo, mid))
+            .max(self.annotate_max_index(mid + 1, hi));
+        self.max_index[mid] = max;
+        max
+    }
+
+    /// The `k` points nearest to point `index` among those with a larger index, nearest first.
+    pub fn k_nearest_after(&self, index: usize, k: usize) -> Vec<(i64, usize)> {
+        let mut best = BinaryHeap::new();
+        self.collect_nearest_after(0, self.len(), 0, index, k, &mut best);
+        best.into_sorted_vec()
+    }
+
+    fn collect_nearest_after(
+        &self,
+        lo: usize,
+        hi: usize,
+        depth: usize,
+        index: usize,
+        k: usize,
+        best: &mut BinaryHeap<(i64, usize)>,
+    ) {
+        if lo >= hi || k == 0 {
+            return;
+        }
+        let mid = lo + (hi - lo) / 2;
+        if self.max_index[mid] <= index {
+            return;
+        }
+        let query = self.points[index];
+        let node = self.order[mid];
+        if node > index {
+            best.push((square_dist(query, self.points[node]), node));
+            if best.len() > k {
+                best.pop();
+            }
+        }
+        let diff = query[depth % 3] - self.points[node][depth % 3];
+        let (near, far) = if diff < 0 {
+            ((lo, mid), (mid + 1, hi))
+        } else {
+            ((mid + 1, hi), (lo, mid))
+        };
+        self.collect_nearest_after(near.0, near.1, depth + 1, index, k, best);
+        if best.len() < k || best.peek().is_some_and(|&(worst, _)| diff * diff <= worst) {
+            self.collect_nearest_after(far.0, far.1, depth + 1, index, k, best);
+        }
+    }
+
+    /// Every pair of points, lazily, in increasing square distance.
+    pub fn pairs_by_distance(&self) -> PairsByDistance<'_> {
+        let mut pairs = PairsByDistance {
+            tree: self,
+            cursors: Vec::with_capacity(self.len()),
+            heap: BinaryHeap::new(),
+        };
+        for index in 0..self.len() {
+            let neighbors = self.k_nearest_after(index, INITIAL_NEIGHBORS);
+            if let Some(&(dist, other)) = neighbors.first() {
+                pairs.heap.push(Reverse((dist, index, other)));
+            }
+            pairs.cursors.push(NeighborCursor {
+                neighbors,
+                requested: INITIAL_NEIGHBORS,
+                next: 1,
+            });
+        }
+        pairs
+    }
+
+    /// The Euclidean minimum spanning tree, found with Borůvka's algorithm. Edges come back
+    /// in no particular order.
+    pub fn minimum_spanning_tree(&self) -> Vec<Edge> {
+        let mut components = DisjointSet::new(self.len());
+        let mut tree = Vec::with_capacity(self.len().saturating_sub(1));
+        while components.component_count() > 1 {
+            let component: Vec<usize> = (0..self.len()).map(|i| components.find(i)).collect();
+            let mut subtree_component = vec![None; self.len()];
+            self.annotate_component(0, self.len(), &component, &mut subtree_component);
+
+            let mut cheapest: Vec<Option<Edge>> = vec![None; self.len()];
+            for index in 0..self.len() {
+                let own = component[index];
+                let search = Search {
+                    index,
+                    own,
+                    component: &component,
+                    subtree_component: &subtree_component,
+                };
+                let mut best = cheapest[own];
+                self.nearest_outside(0, self.len(), 0, &search, &mut best);
+                cheapest[own] = best;
+            }
+            for &(dist, a, b) in cheapest.iter().flatten() {
+                if components.union(a, b).is_some() {
+                    tree.push((dist, a, b));
+                }
+            }
+        }
+        tree
+    }
+
+    fn annotate_component(
+        &self,
+        lo: usize,
+        hi: usize,
+        component: &[usize],
+        subtree_component: &mut [Option<usize>],
+    ) -> Option<Option<usize>> {
+        if lo >= hi {
+            return None;
+        }
+        let mid = lo + (hi - lo) / 2;
+        let own = Some(component[self.order[mid]]);
+        let left = self.annotate_component(lo, mid, component, subtree_component);
+        let right = self.annotate_component(mid + 1, hi, component, subtree_component);
+        let shared = [left, right]
+            .into_iter()
+            .flatten()
+            .all(|child| child == own);
+        subtree_component[mid] = if shared { own } else { None };
+        Some(subtree_component[mid])
+    }
+
+    fn nearest_outside(
+        &self,
+        lo: usize,
+        hi: usize,
+        depth: usize,
+        search: &Search,
+        best: &mut Option<Edge>,
+    ) {
+        if lo >= hi {
+            return;
+        }
+        let mid = lo + (hi - lo) / 2;
+        if search.subtree_component[mid] == Some(search.own) {
+            return;
+        }
+        let query = self.points[search.index];
+        let node = self.order[mid];
+        if search.component[node] != search.own {
+            let candidate = edge(square_dist(query, self.points[node]), search.index, node);
+            if best.is_none_or(|best| candidate < best) {
+                *best = Some(candidate);
+            }
+        }
+        let diff = query[depth % 3] - self.points[node][depth % 3];
+        let (near, far) = if diff < 0 {
+            ((lo, mid), (mid + 1, hi))
+        } else {
+            ((mid + 1, hi), (lo, mid))
+        };
+        self.nearest_outside(near.0, near.1, depth + 1, search, best);
+        if best.is_none_or(|(dist, _, _)| diff * diff <= dist) {
+            self.nearest_outside(far.0, far.1, depth + 1, search, best);
+        }
+    }
+}
+
+fn build(points: &[[i64; 3]], order: &mut [usize], depth: usize) {
+    if order.len() <= 1 {
+        return;
+    }
+    let mid = order.len() / 2;
+    order.select_nth_unstable_by_key(mid, |&i| (points[i][depth % 3], i));
+    let (left, right) = order.split_at_mut(mid);
+    build(points, left, depth + 1);
+    build(points, &mut right[1..], depth + 1);
+}
+
+struct Search<'a> {
+    index: usize,
+    own: usize,
+    component: &'a [usize],
+    subtree_component: &'a [Option<usize>],
+}
+
+const INITIAL_NEIGHBORS: usize = 4;
+
+struct NeighborCursor {
+    neighbors: Vec<(i64, usize)>,
+    requested: usize,
+    next: usize,
+}
+
+/// Streams pairs as `(square_dist, lower index, higher index)`. Each point keeps a buffer of
+/// its nearest higher-indexed neighbours, doubled in size whenever it runs out.
+pub struct PairsByDistance<'a> {
+    tree: &'a KdTree,
+    cursors: Vec<NeighborCursor>,
+    heap: BinaryHeap<Reverse<Edge>>,
+}
+
+impl Iterator for PairsByDistance<'_> {
+    type Item = Edge;
+
+    fn next(&mut self) -> Option<Edge> {
+        let Reverse(pair) = self.heap.pop()?;
+        let index = pair.1;
+        let cursor = &mut self.cursors[index];
+        if cursor.next == cursor.requested {
+            cursor.requested *= 2;
+            cursor.neighbors = self.tree.k_nearest_after(index, cursor.requested);
+        }
+        if let Some(&(dist, other)) = cursor.neighbors.get(cursor.next) {
+            self.heap.push(Reverse((dist, index, other)));
+            cursor.next += 1;
+        }
+        Some(pair)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Small coordinates so that plenty of pairs tie on distance.
+    fn scattered_points(count: usize, seed: u64) -> Vec<[i64; 3]> {
+        let mut state = seed;
+        let mut next = || {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            ((state >> 33) % 20) as i64
+        };
+        (0..count).map(|_| [next(), next(), next()]).collect()
+    }
+
+    fn all_pairs_sorted(points: &[[i64; 3]]) -> Vec<Edge> {
+        let mut pairs = Vec::new();
+        for i in 0..points.len() {
+            for j in (i + 1)..points.len() {
+                pairs.push(edge(square_dist(points[i], points[j]), i, j));
+            }
+        }
+        pairs.sort();
+        pairs
+    }
+
+    #[test]
+    fn pairs_stream_in_the_same_order_as_sorting_all_pairs() {
+        for seed in 0..5 {
+            let points = scattered_points(60, seed);
+            let tree = KdTree::new(&points);
+            let streamed: Vec<Edge> = tree.pairs_by_distance().collect();
+            assert_eq!(streamed, all_pairs_sorted(&points));
+        }
+    }
+
+    #[test]
+    fn k_nearest_after_skips_lower_indices() {
+        let points = [[0, 0, 0], [1, 0, 0], [5, 0, 0], [2, 0, 0]];
+        let tree = KdTree::new(&points);
+        assert_eq!(tree.k_nearest_after(1, 2), vec![(1, 3), (16, 2)]);
+        assert_eq!(tree.k_nearest_after(3, 2), vec![]);
+    }
+
+    #[test]
+    fn minimum_spanning_tree_matches_kruskal() {
+        for seed in 0..5 {
+            let points = scattered_points(80, seed);
+            let mut kruskal = Vec::new();
+            let mut components = DisjointSet::new(points.len());
+            for (dist, a, b) in all_pairs_sorted(&points) {
+                if components.union(a, b).is_some() {
+                    kruskal.push((dist, a, b));
+                }
+            }
+
+            let mut mst = KdTree::new(&points).minimum_spanning_tree();
+            mst.sort();
+            assert_eq!(mst, kruskal);
+        }
+    }
+}
@@ -0,0 +1,28 @@
+//! Solutions to Advent of Code 2025.
+//!
+//! Each puzzle lives in [`days`], with a type implementing [`solution::Solution`] that parses
+//! the input and answers both parts, and the functions behind it for callers that want to
+//! work with the parsed input directly. [`registry::DAYS`] lists every puzzle by number.
+//!
+//! ```
+//! use aoc2025::days::day5::Day5;
+//! use aoc2025::solution::Solution;
+//!
+//! let lines = ["3-5", "10-14", "", "4", "9", "12"].map(String::from).to_vec();
+//! let input = Day5::parse(lines).unwrap();
+//! assert_eq!(Day5::part1(&input).unwrap().unwrap().to_string(), "2");
+//! assert_eq!(Day5::part2(&input).unwrap().unwrap().to_string(), "8");
+//! ```
+
+#![warn(missing_docs)]
+
+pub mod days;
+pub mod error;
+#[cfg(test)]
+mod fixtures;
+pub mod helpers;
+pub mod interval_set;
+pub mod json;
+pub mod kd_tree;
+pub mod registry;
+pub mod solution;
@@ -0,0 +1,75 @@
+//! Every day's solution, looked up by number.
+
+use crate::days;
+use crate::error::AocError;
+use crate::solution::{Params, Solution, SolutionRun, TraceFormat, run_solution, trace_solution};
+
+type RunFn = fn(Vec<String>, &Params, &[u32]) -> Result<SolutionRun, AocError>;
+type TraceFn = fn(Vec<String>, &Params, TraceFormat) -> Result<Option<String>, AocError>;
+
+/// A day's solution with the types erased, so every day fits in one list.
+pub struct Day {
+    /// The day of the puzzle, from 1.
+    pub number: u32,
+    /// What the input is split on before parsing.
+    pub separator: &'static str,
+    /// Parses the input and runs the given parts, timing each.
+    pub run: RunFn,
+    /// Parses the input and returns the day's trace, if it has one.
+    pub trace: TraceFn,
+}
+
+impl Day {
+    /// The entry for solution `S` as day `number`.
+    pub const fn new<S: Solution>(number: u32, separator: &'static str) -> Day {
+        Day {
+            number,
+            separator,
+            run: run_solution::<S>,
+            trace: trace_solution::<S>,
+        }
+    }
+
+    /// The name of the day's input file within an input directory.
+    pub fn input_name(&self) -> String {
+        format!("day{:02}.txt", self.number)
+    }
+}
+
+/// Every registered day, in order.
+pub const DAYS: &[Day] = &[
+    days::day1::DAY,
+    days::day2::DAY,
+    days::day3::DAY,
+    days::day4::DAY,
+    days::day5::DAY,
+    days::day6::DAY,
+    days::day7::DAY,
+    days::day8::DAY,
+    days::day9::DAY,
+];
+
+/// The day numbered `number`, if it is registered.
+pub fn find_day(number: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn days_are_registered_in_order() {
+        assert!(DAYS.windows(2).all(|pair| pair[0].number < pair[1].number));
+    }
+
+    #[test]
+    fn find_day_test() {
+        assert_eq!(find_day(4).map(|day| day.number), Some(4));
+        assert_eq!(
+            find_day(4).map(|day| day.input_name()).as_deref(),
+            Some("day04.txt")
+        );
+        assert!(find_day(26).is_none());
+    }
+}
@@ -0,0 +1,254 @@
+use crate::answers::{AnswerStore, Check};
+use crate::cli::{DaySelection, InputSource, RunArgs, TraceArgs, VerifyArgs};
+use crate::config::Config;
+use crate::inputs::InputDirs;
+use aoc2025::error::AocError;
+use aoc2025::helpers;
+use aoc2025::registry::{self, Day};
+use aoc2025::solution::{Params, SolutionRun};
+use std::path::Path;
+use std::time::Duration;
+
+/// A day's number and its run, or why it could not run.
+type DayRun = (u32, Result<SolutionRun, String>);
+
+pub fn run(args: RunArgs, config: &Config) -> Result<(), String> {
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    let mut store = AnswerStore::load(&config.answers).map_err(|e| e.to_string())?;
+    let dirs = InputDirs::load(args.input_dir.as_deref(), config);
+    let runs = match args.days {
+        DaySelection::One(number) => {
+            let day =
+                registry::find_day(number).ok_or(format!("day {number} is not registered"))?;
+            // Stored answers only apply to the default input and the configured parameters.
+            let check_answers = args.input.is_none() && args.params.is_empty();
+            let params = config.params(number, &args.params);
+            let run = read_input(day, args.input, &dirs)
+                .and_then(|lines| (day.run)(lines, &params, &parts))
+                .map_err(|e| format!("day {number}, {e}"))?;
+            print_run(day, &run, check_answers.then_some(&store));
+            vec![(number, Ok(run))]
+        }
+        DaySelection::All => {
+            let runs = run_all(&parts, &dirs, config);
+            print_summary(&runs, &store);
+            runs
+        }
+    };
+    if args.save {
+        for (day, run) in &runs {
+            let Ok(run) = run else { continue };
+            for part in &run.parts {
+                if let Ok(Some(answer)) = &part.answer {
+                    store.record(*day, part.part, answer);
+                }
+            }
+        }
+        store.save(&config.answers).map_err(|e| e.to_string())?;
+        println!("saved answers to {}", config.answers);
+    }
+    let failed: usize = runs
+        .iter()
+        .map(|(_, run)| match run {
+            Ok(run) => run.parts.iter().filter(|part| part.answer.is_err()).count(),
+            Err(_) => 1,
+        })
+        .sum();
+    match failed {
+        0 => Ok(()),
+        _ => Err(format!("{failed} day(s) or part(s) failed")),
+    }
+}
+
+pub fn verify(args: VerifyArgs, config: &Config) -> Result<(), String> {
+    let store = AnswerStore::load(&config.answers).map_err(|e| e.to_string())?;
+    let dirs = InputDirs::load(args.input_dir.as_deref(), config);
+    let runs = run_all(&[1, 2], &dirs, config);
+    let mut mismatches = 0;
+    for (day, run) in &runs {
+        let run = match run {
+            Ok(run) => run,
+            Err(e) => {
+                mismatches += 1;
+                println!("day {day}: ERROR, {e}");
+                continue;
+            }
+        };
+        for part in &run.parts {
+            let stored = store.get(*day, part.part);
+            let status = match (&part.answer, stored) {
+                (Err(e), _) => {
+                    mismatches += 1;
+                    format!("ERROR, {e}")
+                }
+                (Ok(None), None) => continue,
+                (Ok(None), Some(expected)) => {
+                    mismatches += 1;
+                    format!("MISMATCH, expected {expected} but the part is not implemented")
+                }
+                (Ok(Some(answer)), _) => match store.check(*day, part.part, answer) {
+                    Check::Matches => "ok".to_string(),
+                    Check::Differs(expected) => {
+                        mismatches += 1;
+                        format!("MISMATCH, expected {expected} but got {answer}")
+                    }
+                    Check::Unknown => format!("no stored answer for {answer}"),
+                },
+            };
+            println!("day {day} part {}: {status}", part.part);
+        }
+    }
+    match mismatches {
+        0 => Ok(()),
+        _ => Err(format!(
+            "{mismatches} answer(s) failed or differ from {}",
+            config.answers
+        )),
+    }
+}
+
+pub fn trace(args: TraceArgs, config: &Config) -> Result<(), String> {
+    let number = args.day;
+    let day = registry::find_day(number).ok_or(format!("day {number} is not registered"))?;
+    let dirs = InputDirs::load(args.input_dir.as_deref(), config);
+    let params = config.params(number, &args.params);
+    let trace = read_input(day, args.input, &dirs)
+        .and_then(|lines| (day.trace)(lines, &params, args.format))
+        .map_err(|e| format!("day {number}, {e}"))?;
+    match trace {
+        Some(trace) => {
+            print!("{trace}");
+            Ok(())
+        }
+        None => Err(format!("day {number} has no trace")),
+    }
+}
+
+pub fn selected_days(days: &DaySelection) -> Result<Vec<&'static Day>, String> {
+    match days {
+        DaySelection::One(number) => registry::find_day(*number)
+            .map(|day| vec![day])
+            .ok_or(format!("day {number} is not registered")),
+        DaySelection::All => Ok(registry::DAYS.iter().collect()),
+    }
+}
+
+/// Runs every registered day, keeping going past a day that fails.
+fn run_all(parts: &[u32], dirs: &InputDirs, config: &Config) -> Vec<DayRun> {
+    registry::DAYS
+        .iter()
+        .map(|day| {
+            let params = config.params(day.number, &Params::new());
+            let run = read_input(day, None, dirs)
+                .and_then(|lines| (day.run)(lines, &params, parts))
+                .map_err(|e| e.to_string());
+            (day.number, run)
+        })
+        .collect()
+}
+
+/// Reads the given input, or else the day's input file from the first directory that has it.
+pub fn read_input(
+    day: &Day,
+    input: Option<InputSource>,
+    dirs: &InputDirs,
+) -> Result<Vec<String>, AocError> {
+    match input {
+        Some(InputSource::Stdin) => helpers::get_stdin_separated(day.separator),
+        Some(InputSource::File(path)) => {
+            helpers::get_file_separated(Path::new(&path), day.separator)
+        }
+        None => helpers::get_file_separated(&dirs.resolve(&day.input_name())?, day.separator),
+    }
+}
+
+fn check_label(store: &AnswerStore, day: u32, part: u32, answer: &str) -> &'static str {
+    match store.check(day, part, answer) {
+        Check::Matches => "ok",
+        Check::Differs(_) => "MISMATCH",
+        Check::Unknown => "new",
+    }
+}
+
+fn print_run(day: &Day, run: &SolutionRun, store: Option<&AnswerStore>) {
+    println!("day {} parsed in {:?}", day.number, run.parse_time);
+    for part in &run.parts {
+        let answer = match &part.answer {
+            Ok(Some(answer)) => answer,
+            Ok(None) => {
+                println!("day {} part {}: not implemented", day.number, part.part);
+                continue;
+            }
+            Err(e) => {
+                println!("day {} part {}: error: {e}", day.number, part.part);
+                continue;
+            }
+        };
+        println!(
+            "day {} part {}: {answer}, found in {:?}",
+            day.number, part.part, part.elapsed
+        );
+        if let Some(Check::Differs(expected)) =
+            store.map(|store| store.check(day.number, part.part, answer))
+        {
+            println!("  MISMATCH: stored answer is {expected}");
+        }
+    }
+}
+
+fn print_summary(runs: &[DayRun], store: &AnswerStore) {
+    println!(
+        "{:>3}  {:>4}  {:>20}  {:>12}  {:>12}  {:>8}",
+        "day", "part", "answer", "parse", "solve", "check"
+    );
+    for (day, run) in runs {
+        let Ok(run) = run else {
+            println!(
+                "{:>3}  {:>4}  {:>20}  {:>12}  {:>12}  {:>8}",
+                day, "-", "error", "-", "-", "ERROR"
+            );
+            continue;
+        };
+        for part in &run.parts {
+            let (answer, elapsed, check) = match &part.answer {
+                Ok(Some(answer)) => (
+                    answer.as_str(),
+                    format!("{:.2?}", part.elapsed),
+                    check_label(store, *day, part.part, answer),
+                ),
+                Ok(None) => ("-", "-".to_string(), "-"),
+                Err(_) => ("error", format!("{:.2?}", part.elapsed), "ERROR"),
+            };
+            println!(
+                "{:>3}  {:>4}  {:>20}  {:>12}  {:>12}  {:>8}",
+                day,
+                part.part,
+                answer,
+                format!("{:.2?}", run.parse_time),
+                elapsed,
+                check
+            );
+        }
+    }
+    let total: Duration = runs
+        .iter()
+        .filter_map(|(_, run)| run.as_ref().ok())
+        .map(|run| run.parse_time + run.parts.iter().map(|part| part.elapsed).sum())
+        .sum();
+    println!("total time: {total:.2?}");
+    for (day, run) in runs {
+        match run {
+            Ok(run) => {
+                for part in &run.parts {
+                    if let Err(e) = &part.answer {
+                        println!("day {day} part {}: error: {e}", part.part);
+                    }
+                }
+            }
+            Err(e) => println!("day {day}: error: {e}"),
+        }
+    }
+}
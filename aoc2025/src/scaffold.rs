@@ -0,0 +1,291 @@
+use crate::cli::NewArgs;
+use crate::config::{Config, WORKSPACE_ROOT};
+use aoc2025::error::AocError;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+const TEMPLATE: &str = r#"//! Day {n}.
+
+use crate::error::AocError;
+use crate::registry::Day;
+use crate::solution::Solution;
+use std::fmt::Display;
+
+/// The registry entry for day {n}, with the input split into lines.
+pub const DAY: Day = Day::new::<Day{n}>({n}, "\n");
+
+/// Parses and solves day {n}.
+pub struct Day{n};
+
+impl Solution for Day{n} {
+    type Input = Vec<String>;
+
+    fn parse(lines: Vec<String>) -> Result<Self::Input, AocError> {
+        Ok(lines)
+    }
+
+    fn part1(_input: &Self::Input) -> Result<Option<impl Display>, AocError> {
+        Ok(None::<String>)
+    }
+
+    fn part2(_input: &Self::Input) -> Result<Option<impl Display>, AocError> {
+        Ok(None::<String>)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+
+    #[test]
+    fn parse_example() {
+        assert!(Day{n}::parse(fixtures::example_lines({n}, "example")).is_ok());
+    }
+}
+"#;
+
+/// Where a new day's files go: the crate's `src`, the input directory, and the examples.
+pub struct Layout {
+    pub src: PathBuf,
+    pub inputs: PathBuf,
+    pub examples: PathBuf,
+}
+
+pub fn scaffold(args: NewArgs, config: &Config) -> Result<(), String> {
+    let root = Path::new(WORKSPACE_ROOT);
+    let layout = Layout {
+        src: Path::new(env!("CARGO_MANIFEST_DIR")).join("src"),
+        inputs: config
+            .input_dir
+            .clone()
+            .unwrap_or(root.join(config.default_input_dir())),
+        examples: root.join("examples"),
+    };
+    let created = new_day(&layout, args.day).map_err(|e| format!("day {}, {e}", args.day))?;
+    for path in created {
+        println!("created {}", path.display());
+    }
+    println!("registered day {} in days/mod.rs and registry.rs", args.day);
+    println!("write the example's answers to example.expected to test it, e.g. part1 = 42");
+    Ok(())
+}
+
+/// Writes `days/dayN.rs`, an empty input and an empty example for `day`, and registers the
+/// module in `days/mod.rs` and `registry.rs`. Nothing is written if any of the files exist or
+/// the day is already registered, and if a write fails the files created so far are removed
+/// and both registrations restored. Returns the files created.
+pub fn new_day(layout: &Layout, day: u32) -> Result<Vec<PathBuf>, AocError> {
+    let module = layout.src.join("days").join(format!("day{day}.rs"));
+    let input = layout.inputs.join(format!("day{day:02}.txt"));
+    let example = layout
+        .examples
+        .join(format!("day{day:02}"))
+        .join("example.txt");
+    let created = vec![module.clone(), input.clone(), example.clone()];
+    if let Some(existing) = created.iter().find(|path| path.exists()) {
+        return Err(AocError::Invalid(format!(
+            "{} already exists, not overwriting it",
+            existing.display()
+        )));
+    }
+
+    let days_path = layout.src.join("days/mod.rs");
+    let registry_path = layout.src.join("registry.rs");
+    let (days_source, registry_source) = (read(&days_path)?, read(&registry_path)?);
+    let days = register_module(&days_source, day)?;
+    let registry = register_day(&registry_source, day)?;
+
+    let template = TEMPLATE.replace("{n}", &day.to_string());
+    let written = write_new(&module, &template)
+        .and_then(|_| write_new(&input, ""))
+        .and_then(|_| write_new(&example, ""))
+        .and_then(|_| write(&days_path, &days))
+        .and_then(|_| write(&registry_path, &registry));
+    if let Err(e) = written {
+        for path in &created {
+            let _ = fs::remove_file(path);
+        }
+        let _ = fs::write(&days_path, days_source);
+        let _ = fs::write(&registry_path, registry_source);
+        return Err(e);
+    }
+    Ok(created)
+}
+
+fn read(path: &Path) -> Result<String, AocError> {
+    fs::read_to_string(path).map_err(|e| AocError::io(&path.display().to_string(), e))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), AocError> {
+    fs::write(path, contents).map_err(|e| AocError::io(&path.display().to_string(), e))
+}
+
+fn write_new(path: &Path, contents: &str) -> Result<(), AocError> {
+    let name = path.display().to_string();
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| AocError::io(&name, e))?;
+    }
+    OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(contents.as_bytes()))
+        .map_err(|e| AocError::io(&name, e))
+}
+
+/// The day number in `text` if it is `day` followed only by digits.
+fn day_number(text: &str) -> Option<u32> {
+    text.strip_prefix("day")?.parse().ok()
+}
+
+/// Inserts `line` after the last line for a lower day, as picked out by `day_of`.
+fn insert_in_day_order(
+    source: &str,
+    day: u32,
+    line: &str,
+    day_of: impl Fn(&str) -> Option<u32>,
+) -> Result<String, AocError> {
+    let lines: Vec<&str> = source.lines().collect();
+    if lines.iter().any(|text| day_of(text) == Some(day)) {
+        return Err(AocError::Invalid(format!(
+            "day {day} is already registered"
+        )));
+    }
+    let Some(last) = lines
+        .iter()
+        .rposition(|text| day_of(text).is_some_and(|d| d < day))
+    else {
+        return Err(AocError::Invalid(format!(
+            "found nowhere to register day {day}"
+        )));
+    };
+    let mut updated: Vec<&str> = lines[..=last].to_vec();
+    updated.push(line);
+    updated.extend(&lines[last + 1..]);
+    Ok(updated.join("\n") + "\n")
+}
+
+fn register_module(days: &str, day: u32) -> Result<String, AocError> {
+    insert_in_day_order(days, day, &format!("pub mod day{day};"), |text| {
+        day_number(text.strip_prefix("pub mod ")?.strip_suffix(';')?)
+    })
+}
+
+/// Days are named by path in `DAYS`, so registering one is a single new line.
+fn register_day(registry: &str, day: u32) -> Result<String, AocError> {
+    insert_in_day_order(
+        registry,
+        day,
+        &format!("    days::day{day}::DAY,"),
+        |text| day_number(text.trim().strip_prefix("days::")?.strip_suffix("::DAY,")?),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc2025::registry;
+
+    const DAYS: &str = "//! Puzzles.\n\npub mod day1;\npub mod day2;\n";
+    const REGISTRY: &str = "use crate::days;\n\npub const DAYS: &[Day] = &[\n    \
+                            days::day1::DAY,\n    days::day2::DAY,\n];\n";
+
+    #[test]
+    fn modules_are_registered_in_day_order() {
+        assert_eq!(
+            register_module(DAYS, 10).unwrap(),
+            "//! Puzzles.\n\npub mod day1;\npub mod day2;\npub mod day10;\n"
+        );
+        assert_eq!(
+            register_day(REGISTRY, 10).unwrap(),
+            "use crate::days;\n\npub const DAYS: &[Day] = &[\n    days::day1::DAY,\n    \
+             days::day2::DAY,\n    days::day10::DAY,\n];\n"
+        );
+        assert_eq!(
+            register_module(DAYS, 2).unwrap_err().to_string(),
+            "day 2 is already registered"
+        );
+    }
+
+    #[test]
+    fn new_day_writes_files_once() {
+        let root = std::env::temp_dir().join(format!("aoc2025-scaffold-{}", std::process::id()));
+        let layout = Layout {
+            src: root.join("src"),
+            inputs: root.join("inputs"),
+            examples: root.join("examples"),
+        };
+        fs::create_dir_all(layout.src.join("days")).unwrap();
+        fs::write(layout.src.join("days/mod.rs"), DAYS).unwrap();
+        fs::write(layout.src.join("registry.rs"), REGISTRY).unwrap();
+
+        let created = new_day(&layout, 10).unwrap();
+        assert_eq!(created[1], root.join("inputs/day10.txt"));
+        let module = fs::read_to_string(layout.src.join("days/day10.rs")).unwrap();
+        assert!(module.contains("pub const DAY: Day = Day::new::<Day10>(10, \"\\n\");"));
+        assert!(module.contains("fixtures::example_lines(10, \"example\")"));
+        assert_eq!(
+            fs::read_to_string(root.join("examples/day10/example.txt")).unwrap(),
+            ""
+        );
+
+        assert_eq!(
+            new_day(&layout, 10).unwrap_err().to_string(),
+            format!(
+                "{} already exists, not overwriting it",
+                created[0].display()
+            )
+        );
+        fs::remove_file(&created[0]).unwrap();
+        fs::remove_file(&created[1]).unwrap();
+        fs::remove_file(&created[2]).unwrap();
+        assert_eq!(
+            new_day(&layout, 10).unwrap_err().to_string(),
+            "day 10 is already registered"
+        );
+        assert!(!created[0].exists());
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn the_real_registry_takes_a_new_day() {
+        let last = registry::DAYS.last().map_or(0, |day| day.number);
+        let next = last + 1;
+        let registry = register_day(include_str!("registry.rs"), next).unwrap();
+        assert!(registry.contains(&format!(
+            "    days::day{last}::DAY,\n    days::day{next}::DAY,\n];"
+        )));
+        let days = register_module(include_str!("days/mod.rs"), next).unwrap();
+        assert!(days.contains(&format!("pub mod day{last};\npub mod day{next};\n")));
+    }
+
+    #[test]
+    fn a_failed_write_leaves_nothing_behind() {
+        let root = std::env::temp_dir().join(format!("aoc2025-rollback-{}", std::process::id()));
+        let layout = Layout {
+            src: root.join("src"),
+            inputs: root.join("inputs"),
+            examples: root.join("examples"),
+        };
+        fs::create_dir_all(layout.src.join("days")).unwrap();
+        fs::write(layout.src.join("days/mod.rs"), DAYS).unwrap();
+        fs::write(layout.src.join("registry.rs"), REGISTRY).unwrap();
+        // A file where the examples directory should be stops the third write.
+        fs::write(&layout.examples, "").unwrap();
+
+        assert!(new_day(&layout, 10).is_err());
+        assert!(!layout.src.join("days/day10.rs").exists());
+        assert!(!layout.inputs.join("day10.txt").exists());
+        assert_eq!(
+            fs::read_to_string(layout.src.join("days/mod.rs")).unwrap(),
+            DAYS
+        );
+        assert_eq!(
+            fs::read_to_string(layout.src.join("registry.rs")).unwrap(),
+            REGISTRY
+        );
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,250 @@
+//! The [`Solution`] trait each day implements, and the functions that run one and time it.
+
+use crate::error::AocError;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+/// One day's puzzle: how to parse its input and answer each part.
+pub trait Solution {
+    /// The parsed input, along with any settings [`Solution::configure`] fills in.
+    type Input;
+
+    /// The names `configure` reads from [`Params`]; any other name is rejected.
+    const PARAMS: &'static [&'static str] = &[];
+
+    /// Parses the input, already split on the day's separator.
+    fn parse(lines: Vec<String>) -> Result<Self::Input, AocError>;
+
+    /// Adjusts the parsed input for parameters given on the command line.
+    fn configure(_input: &mut Self::Input, _params: &Params) -> Result<(), AocError> {
+        Ok(())
+    }
+
+    /// The answer to part 1, `None` if it is not solved yet, or an error if this input has no
+    /// answer the part can give.
+    fn part1(_input: &Self::Input) -> Result<Option<impl Display>, AocError> {
+        Ok(None::<String>)
+    }
+
+    /// The answer to part 2, `None` if it is not solved yet, or an error if this input has no
+    /// answer the part can give.
+    fn part2(_input: &Self::Input) -> Result<Option<impl Display>, AocError> {
+        Ok(None::<String>)
+    }
+
+    /// A step-by-step account of how the answer comes about, for days that have one.
+    fn trace(_input: &Self::Input, _format: TraceFormat) -> Option<String> {
+        None
+    }
+}
+
+/// How a trace is written out: readable text, or one JSON object per line.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum TraceFormat {
+    /// Lines of prose or drawings, given as `text`.
+    #[default]
+    Text,
+    /// One JSON object per line, given as `json`.
+    JsonLines,
+}
+
+impl FromStr for TraceFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<TraceFormat, String> {
+        match s {
+            "text" => Ok(TraceFormat::Text),
+            "json" => Ok(TraceFormat::JsonLines),
+            _ => Err(format!("expected text or json, found '{s}'")),
+        }
+    }
+}
+
+/// Named settings for a day, given as `name=value` on the command line.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Params {
+    values: BTreeMap<String, String>,
+}
+
+impl Params {
+    /// No parameters.
+    pub fn new() -> Params {
+        Params::default()
+    }
+
+    /// Sets `name` to `value`, replacing any value it had.
+    pub fn set(&mut self, name: &str, value: &str) {
+        self.values.insert(name.to_string(), value.to_string());
+    }
+
+    /// Whether no parameters were given.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Sets every parameter in `overrides`, replacing any value already here.
+    pub fn extend(&mut self, overrides: &Params) {
+        self.values.extend(overrides.values.clone());
+    }
+
+    /// Parses the named parameter, or returns `default` when it was not given.
+    pub fn get_or<T>(&self, name: &str, default: T) -> Result<T, AocError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        match self.values.get(name) {
+            Some(value) => value
+                .parse()
+                .map_err(|e| AocError::Invalid(format!("parameter '{name}': {e}"))),
+            None => Ok(default),
+        }
+    }
+
+    fn check_names(&self, known: &[&str]) -> Result<(), AocError> {
+        match self
+            .values
+            .keys()
+            .find(|name| !known.contains(&name.as_str()))
+        {
+            Some(name) => Err(AocError::Invalid(format!("unknown parameter '{name}'"))),
+            None => Ok(()),
+        }
+    }
+}
+
+/// The outcome of running one part.
+pub struct PartRun {
+    /// Which part, 1 or 2.
+    pub part: u32,
+    /// The answer, `None` if the part is not solved, or why the part failed.
+    pub answer: Result<Option<String>, AocError>,
+    /// How long the part took.
+    pub elapsed: Duration,
+}
+
+/// The outcome of running a day.
+pub struct SolutionRun {
+    /// How long parsing and configuring the input took.
+    pub parse_time: Duration,
+    /// Each part that was asked for, in the order asked.
+    pub parts: Vec<PartRun>,
+}
+
+/// Parses `lines`, applies `params` and runs each of `parts`. An error parsing or configuring
+/// stops the run, while a part that fails keeps its error and the other parts still run.
+pub fn run_solution<S: Solution>(
+    lines: Vec<String>,
+    params: &Params,
+    parts: &[u32],
+) -> Result<SolutionRun, AocError> {
+    params.check_names(S::PARAMS)?;
+    let start = Instant::now();
+    let mut input = S::parse(lines)?;
+    S::configure(&mut input, params)?;
+    let parse_time = start.elapsed();
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = match part {
+                1 => S::part1(&input).map(|answer| answer.map(|answer| answer.to_string())),
+                2 => S::part2(&input).map(|answer| answer.map(|answer| answer.to_string())),
+                _ => Ok(None),
+            };
+            PartRun {
+                part,
+                answer,
+                elapsed: start.elapsed(),
+            }
+        })
+        .collect();
+    Ok(SolutionRun { parse_time, parts })
+}
+
+/// Parses `lines`, applies `params` and returns the trace in `format`, if the day has one.
+pub fn trace_solution<S: Solution>(
+    lines: Vec<String>,
+    params: &Params,
+    format: TraceFormat,
+) -> Result<Option<String>, AocError> {
+    params.check_names(S::PARAMS)?;
+    let mut input = S::parse(lines)?;
+    S::configure(&mut input, params)?;
+    Ok(S::trace(&input, format))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct LineCount;
+
+    impl Solution for LineCount {
+        type Input = usize;
+
+        fn parse(lines: Vec<String>) -> Result<usize, AocError> {
+            Ok(lines.len())
+        }
+
+        fn part1(count: &usize) -> Result<Option<impl Display>, AocError> {
+            Ok(Some(*count))
+        }
+
+        fn part2(count: &usize) -> Result<Option<impl Display>, AocError> {
+            match count {
+                0 => Err(AocError::Invalid("no lines to count".to_string())),
+                _ => Ok(None::<usize>),
+            }
+        }
+    }
+
+    #[test]
+    fn run_solution_reuses_parsed_input() {
+        let lines = vec!["a".to_string(), "b".to_string()];
+        let run = run_solution::<LineCount>(lines, &Params::new(), &[1, 2]).unwrap();
+        let answers: Vec<(u32, Option<String>)> = run
+            .parts
+            .into_iter()
+            .map(|part| (part.part, part.answer.unwrap()))
+            .collect();
+        assert_eq!(answers, vec![(1, Some("2".to_string())), (2, None)]);
+    }
+
+    #[test]
+    fn a_failing_part_keeps_its_error_and_the_others_still_run() {
+        let run = run_solution::<LineCount>(vec![], &Params::new(), &[2, 1]).unwrap();
+        assert_eq!(
+            run.parts[0].answer.as_ref().unwrap_err().to_string(),
+            "no lines to count"
+        );
+        assert_eq!(
+            run.parts[1].answer.as_ref().unwrap(),
+            &Some("0".to_string())
+        );
+    }
+
+    #[test]
+    fn params_are_parsed_and_checked() {
+        let mut params = Params::new();
+        params.set("start", "40");
+        assert_eq!(params.get_or("start", 50).unwrap(), 40);
+        assert_eq!(params.get_or("size", 100).unwrap(), 100);
+        assert_eq!(
+            params
+                .get_or::<bool>("start", false)
+                .unwrap_err()
+                .to_string(),
+            "parameter 'start': provided string was not `true` or `false`"
+        );
+        assert!(params.check_names(&["start"]).is_ok());
+        assert_eq!(
+            run_solution::<LineCount>(vec![], &params, &[1])
+                .err()
+                .map(|e| e.to_string()),
+            Some("unknown parameter 'start'".to_string())
+        );
+    }
+}